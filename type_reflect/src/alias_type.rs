@@ -1,4 +1,4 @@
-use type_reflect_core::type_description::Type;
+use type_reflect_core::type_description::{Documentation, Metadata, Type};
 
 /// A type implementing `AliasType` can
 /// be used to emit a type alias representation
@@ -6,4 +6,14 @@ pub trait AliasType {
     fn name() -> &'static str;
     fn source_type() -> Type;
     fn rust() -> String;
+    fn docs() -> Documentation {
+        Documentation::default()
+    }
+    fn meta() -> Metadata {
+        Metadata::default()
+    }
+    /// The serialized `Default` value of the type, if it's declared with `#[reflect(default)]`
+    fn default_value() -> Option<serde_json::Value> {
+        None
+    }
 }
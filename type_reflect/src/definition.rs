@@ -0,0 +1,170 @@
+use serde_json::Value;
+use type_reflect_core::{EnumCase, EnumType, Inflection, Type, TypeFieldsDefinition};
+
+use crate::{option_style, type_overrides::definition, AliasType, EnumReflectionType, StructType};
+
+/// The reflected definition of a type, which describes the shape of its serialized values
+///
+/// The definitions of the exported types are installed alongside the type overrides,
+/// so that values which reference them can be rendered, i.e. by `json_literal`.
+#[derive(Clone, Debug)]
+pub enum Definition {
+    Struct {
+        fields: TypeFieldsDefinition,
+        inflection: Inflection,
+    },
+    Enum {
+        cases: Vec<EnumCase>,
+        enum_type: EnumType,
+        inflection: Inflection,
+    },
+    Alias(Type),
+}
+
+impl Definition {
+    pub fn of_struct<T: StructType + ?Sized>() -> Self {
+        Definition::Struct {
+            fields: T::fields(),
+            inflection: T::inflection(),
+        }
+    }
+
+    pub fn of_enum<T: EnumReflectionType + ?Sized>() -> Self {
+        Definition::Enum {
+            cases: T::cases(),
+            enum_type: T::enum_type(),
+            inflection: T::inflection(),
+        }
+    }
+
+    pub fn of_alias<T: AliasType + ?Sized>() -> Self {
+        Definition::Alias(T::source_type())
+    }
+
+    /// Renders a serialized value of the type as a TypeScript expression
+    pub fn literal(&self, value: Value) -> String {
+        self.omit_nulls(value).to_string()
+    }
+
+    fn omit_nulls(&self, value: Value) -> Value {
+        match self {
+            Definition::Struct { fields, inflection } => {
+                fields_omit_nulls(fields, *inflection, value)
+            }
+            Definition::Enum {
+                cases,
+                enum_type,
+                inflection,
+            } => enum_omit_nulls(cases, enum_type, *inflection, value),
+            Definition::Alias(type_) => omit_nulls(type_, value),
+        }
+    }
+}
+
+/// Removes the `null` members of the `Option` fields which are emitted as optional
+/// rather than nullable, since those may be omitted but are never `null`
+///
+/// Values of named types are only walked if their definitions are installed,
+/// and other `null`s, such as those of an `Option` array item or map value, are kept.
+pub(crate) fn omit_nulls(type_: &Type, value: Value) -> Value {
+    match type_ {
+        Type::Named(named) => match definition(&named.name) {
+            Some(definition) => definition.omit_nulls(value),
+            None => value,
+        },
+        Type::Option(type_) => match value {
+            Value::Null => Value::Null,
+            value => omit_nulls(type_, value),
+        },
+        Type::Array(type_) => match value {
+            Value::Array(items) => items
+                .into_iter()
+                .map(|item| omit_nulls(type_, item))
+                .collect(),
+            value => value,
+        },
+        Type::Map { value: type_, .. } => match value {
+            Value::Object(entries) => entries
+                .into_iter()
+                .map(|(key, value)| (key, omit_nulls(type_, value)))
+                .collect(),
+            value => value,
+        },
+        Type::Transparent(transparent) => omit_nulls(&transparent.type_, value),
+        Type::Override { type_, .. } | Type::Constrained { type_, .. } => omit_nulls(type_, value),
+        Type::Inline(inline) => fields_omit_nulls(&inline.fields, inline.inflection, value),
+        _ => value,
+    }
+}
+
+fn fields_omit_nulls(fields: &TypeFieldsDefinition, inflection: Inflection, value: Value) -> Value {
+    match (fields, value) {
+        (TypeFieldsDefinition::Named(fields), Value::Object(entries)) => entries
+            .into_iter()
+            .filter_map(|(key, value)| {
+                let Some(field) = fields
+                    .iter()
+                    .find(|field| field.serialized_name(inflection) == key)
+                else {
+                    return Some((key, value));
+                };
+                let omitted = matches!(field.type_, Type::Option(_))
+                    && value.is_null()
+                    && !option_style(field.option_style).is_nullable();
+                (!omitted).then(|| (key, omit_nulls(&field.type_, value)))
+            })
+            .collect(),
+        // A newtype is serialized as its content
+        (TypeFieldsDefinition::Tuple(items), value) if items.len() == 1 => {
+            omit_nulls(&items[0], value)
+        }
+        (TypeFieldsDefinition::Tuple(items), Value::Array(values)) => items
+            .iter()
+            .zip(values)
+            .map(|(type_, value)| omit_nulls(type_, value))
+            .collect(),
+        (_, value) => value,
+    }
+}
+
+/// Only the cases of tagged enums can be identified, so the values of
+/// untagged enums are kept as they are
+fn enum_omit_nulls(
+    cases: &[EnumCase],
+    enum_type: &EnumType,
+    inflection: Inflection,
+    value: Value,
+) -> Value {
+    let EnumType::Complex {
+        case_key,
+        content_key,
+    } = enum_type
+    else {
+        return value;
+    };
+    let Value::Object(mut entries) = value else {
+        return value;
+    };
+    let case = entries
+        .get(case_key)
+        .and_then(Value::as_str)
+        .and_then(|tag| {
+            cases
+                .iter()
+                .find(|case| case.serialized_name(inflection) == tag)
+        });
+    let Some(case) = case else {
+        return Value::Object(entries);
+    };
+    match content_key {
+        Some(content_key) => {
+            if let Some(content) = entries.remove(content_key) {
+                let content = fields_omit_nulls(&case.type_, case.inflection, content);
+                entries.insert(content_key.clone(), content);
+            }
+            Value::Object(entries)
+        }
+        // An internally tagged case is serialized with its fields alongside the tag
+        None => fields_omit_nulls(&case.type_, case.inflection, Value::Object(entries)),
+    }
+}
@@ -1,5 +1,5 @@
 use type_reflect_core::{
-    type_description::{EnumCase, EnumType},
+    type_description::{Documentation, EnumCase, EnumType, Metadata},
     Inflection,
 };
 
@@ -11,4 +11,41 @@ pub trait EnumReflectionType {
     fn cases() -> Vec<EnumCase>;
     fn enum_type() -> EnumType;
     fn rust() -> String;
+    fn docs() -> Documentation {
+        Documentation::default()
+    }
+    fn meta() -> Metadata {
+        Metadata::default()
+    }
+    /// The serialized `Default` value of the type, if it's declared with `#[reflect(default)]`
+    fn default_value() -> Option<serde_json::Value> {
+        None
+    }
+    /// Whether unknown fields are rejected, i.e. with `#[serde(deny_unknown_fields)]`
+    fn deny_unknown_fields() -> bool {
+        false
+    }
+}
+
+/// Renders the `#[reflect(default)]` value of an enum as a TypeScript expression
+///
+/// Members of simple and numeric enums are referenced through the enum, i.e. `Theme.Light`,
+/// since a string literal isn't assignable to a TypeScript string enum.
+pub fn enum_default_literal<T: EnumReflectionType + ?Sized>() -> Option<String> {
+    let value = T::default_value()?;
+    if let (EnumType::Simple, serde_json::Value::String(serialized)) = (T::enum_type(), &value)
+        && let Some(case) = T::cases()
+            .into_iter()
+            .find(|case| &case.serialized_name(T::inflection()) == serialized)
+    {
+        return Some(format!("{}.{}", T::name(), case.name));
+    }
+    if let (EnumType::Numeric, Some(discriminant)) = (T::enum_type(), value.as_i64())
+        && let Some(case) = T::cases()
+            .into_iter()
+            .find(|case| case.discriminant == Some(discriminant))
+    {
+        return Some(format!("{}.{}", T::name(), case.name));
+    }
+    Some(crate::Definition::of_enum::<T>().literal(value))
 }
@@ -0,0 +1,266 @@
+use std::ffi::OsStr;
+
+use super::*;
+use crate::type_script::{to_ts_type, union_case_type_name};
+
+/// Emits JSDoc `@typedef` comments, which give editor type checking
+/// to plain JavaScript files using `// @ts-check`
+#[derive(Default)]
+pub struct JSDoc {
+    /// How `Option` fields are represented, unless overridden
+    /// with `#[reflect(option_style = "..")]`
+    pub option_style: OptionStyle,
+}
+
+/// Renders a type as a JSDoc type expression
+///
+/// JSDoc type expressions accept TypeScript syntax, so this defers to `to_ts_type`,
+/// except that inline struct types are kept on a single line and without member docs,
+/// since they're rendered inside of a comment.
+fn to_jsdoc_type(t: &Type) -> String {
+    match t {
+        // Only a field can be omitted, so any other `None` is `null`
+        Type::Option(t) => format!("{} | null", to_jsdoc_type(t)),
+        Type::Array(t) => format!("Array<{}>", to_jsdoc_type(t)),
+        Type::Map { key, value } => format!(
+            "{{[key: {k}]: {v}}}",
+            k = to_jsdoc_type(key),
+            v = to_jsdoc_type(value)
+        ),
+        Type::Transparent(t) => to_jsdoc_type(&t.type_),
+        Type::Constrained { type_, .. } => to_jsdoc_type(type_),
+        Type::Inline(inline) => fields_type(&inline.fields, inline.inflection),
+        t => to_ts_type(t),
+    }
+}
+
+/// Renders the type of a named field, and whether the field may be omitted
+fn field_type(field: &NamedField) -> (String, bool) {
+    match &field.type_ {
+        Type::Option(t) => {
+            let style = option_style(field.option_style);
+            let type_ = match style.is_nullable() {
+                true => format!("{} | null", to_jsdoc_type(t)),
+                false => to_jsdoc_type(t),
+            };
+            (type_, style.is_optional())
+        }
+        t => (to_jsdoc_type(t), false),
+    }
+}
+
+/// Renders the fields of a struct or enum case as a single type expression
+fn fields_type(fields: &TypeFieldsDefinition, inflection: Inflection) -> String {
+    match fields {
+        // A unit struct is serialized as `null`
+        TypeFieldsDefinition::Unit => "null".to_string(),
+        TypeFieldsDefinition::Tuple(items) if items.len() == 1 => to_jsdoc_type(&items[0]),
+        TypeFieldsDefinition::Tuple(items) => {
+            let items: Vec<String> = items.iter().map(to_jsdoc_type).collect();
+            format!("[{}]", items.join(", "))
+        }
+        TypeFieldsDefinition::Named(named) => {
+            let members: Vec<String> = named
+                .iter()
+                .map(|field| {
+                    let (type_, optional) = field_type(field);
+                    let optional = if optional { "?" } else { "" };
+                    format!("{}{optional}: {type_}", field.serialized_name(inflection))
+                })
+                .collect();
+            format!("{{ {} }}", members.join(", "))
+        }
+    }
+}
+
+/// Renders the `@property` tag of a named field, without the tag name
+fn property(field: &NamedField, inflection: Inflection) -> String {
+    let (type_, optional) = field_type(field);
+    let name = field.serialized_name(inflection);
+    let name = if optional { format!("[{name}]") } else { name };
+    match &field.docs.description {
+        Some(description) => {
+            let description: Vec<&str> = description
+                .lines()
+                .map(str::trim)
+                .filter(|line| !line.is_empty())
+                .collect();
+            format!("{{{type_}}} {name} {}", description.join(" "))
+        }
+        None => format!("{{{type_}}} {name}"),
+    }
+}
+
+/// Renders a JSDoc comment declaring `name` as `type_`, followed by
+/// a `@property` tag for each of the rendered `properties`
+fn typedef(name: &str, type_: &str, properties: &[String], docs: &Documentation) -> String {
+    let mut lines: Vec<String> = vec![];
+    if let Some(description) = &docs.description {
+        lines.extend(description.lines().map(|line| line.to_string()));
+    }
+    if let Some(deprecated) = &docs.deprecated {
+        lines.push(format!("@deprecated {deprecated}"));
+    }
+    lines.push(format!("@typedef {{{type_}}} {name}"));
+    lines.extend(
+        properties
+            .iter()
+            .map(|property| format!("@property {property}")),
+    );
+
+    let lines: Vec<String> = lines
+        .iter()
+        .map(|line| {
+            format!(" * {}", line.replace("*/", "*\\/"))
+                .trim_end()
+                .to_string()
+        })
+        .collect();
+
+    format!("\n/**\n{}\n */\n", lines.join("\n"))
+}
+
+fn emit_complex_enum<T>(case_key: &str, content_key: &Option<String>) -> String
+where
+    T: EnumReflectionType,
+{
+    let name = T::name();
+    let mut result = String::new();
+    let mut case_types = vec![];
+
+    for case in T::cases() {
+        let case_type_name = union_case_type_name(&case, name);
+        let tag = format!(
+            r#"{{"{}"}} {case_key}"#,
+            case.serialized_name(T::inflection())
+        );
+
+        let case_typedef = match (&case.type_, content_key) {
+            (TypeFieldsDefinition::Unit, _) => {
+                typedef(&case_type_name, "Object", &[tag], &case.docs)
+            }
+            (fields, Some(content_key)) => {
+                let content = format!("{{{}}} {content_key}", fields_type(fields, case.inflection));
+                typedef(&case_type_name, "Object", &[tag, content], &case.docs)
+            }
+            // An internally tagged newtype variant merges the fields of its content with the tag
+            (TypeFieldsDefinition::Tuple(_), None) => {
+                let type_ = format!(
+                    r#"{{ {}: "{}" }} & {}"#,
+                    serde_json::to_string(case_key).expect("a string always serializes"),
+                    case.serialized_name(T::inflection()),
+                    fields_type(&case.type_, case.inflection)
+                );
+                typedef(&case_type_name, &type_, &[], &case.docs)
+            }
+            (TypeFieldsDefinition::Named(named), None) => {
+                let mut properties = vec![tag];
+                properties.extend(named.iter().map(|field| property(field, case.inflection)));
+                typedef(&case_type_name, "Object", &properties, &case.docs)
+            }
+        };
+        result.push_str(&case_typedef);
+        case_types.push(case_type_name);
+    }
+
+    result.push_str(&typedef(name, &case_types.join(" | "), &[], &T::docs()));
+    result
+}
+
+/// Emits an externally tagged enum, where unit cases are serialized as their name
+/// and every other case as an object with its name as the only key
+fn emit_untagged_enum<T>() -> String
+where
+    T: EnumReflectionType,
+{
+    let cases: Vec<String> = T::cases()
+        .iter()
+        .map(|case| {
+            // The name is quoted, since it's not always a valid identifier
+            let id = serde_json::to_string(&case.serialized_name(T::inflection()))
+                .expect("a string always serializes");
+            match &case.type_ {
+                TypeFieldsDefinition::Unit => id,
+                fields => format!("{{ {id}: {} }}", fields_type(fields, case.inflection)),
+            }
+        })
+        .collect();
+    let type_ = match cases.is_empty() {
+        true => "never".to_string(),
+        false => cases.join(" | "),
+    };
+    typedef(T::name(), &type_, &[], &T::docs())
+}
+
+impl TypeEmitter for JSDoc {
+    /// An empty export makes the destination a module,
+    /// so that its typedefs can be imported with `import("./types.js").Name`
+    fn prefix(&mut self) -> String {
+        "// @ts-check\n\nexport {};\n".to_string()
+    }
+
+    fn emit_struct<T>(&mut self) -> String
+    where
+        T: StructType,
+    {
+        with_option_style(self.option_style, || match T::fields() {
+            TypeFieldsDefinition::Named(named) => {
+                let properties: Vec<String> = named
+                    .iter()
+                    .map(|field| property(field, T::inflection()))
+                    .collect();
+                typedef(T::name(), "Object", &properties, &T::docs())
+            }
+            fields => typedef(
+                T::name(),
+                &fields_type(&fields, T::inflection()),
+                &[],
+                &T::docs(),
+            ),
+        })
+    }
+
+    fn emit_enum<T>(&mut self) -> String
+    where
+        T: EnumReflectionType,
+    {
+        with_option_style(self.option_style, || match T::enum_type() {
+            EnumType::Simple => {
+                let cases: Vec<String> = T::cases()
+                    .iter()
+                    .map(|case| format!(r#""{}""#, case.serialized_name(T::inflection())))
+                    .collect();
+                typedef(T::name(), &cases.join(" | "), &[], &T::docs())
+            }
+            EnumType::Numeric => {
+                let cases: Vec<String> = T::cases()
+                    .iter()
+                    .map(|case| case.discriminant.unwrap_or_default().to_string())
+                    .collect();
+                typedef(T::name(), &cases.join(" | "), &[], &T::docs())
+            }
+            EnumType::Complex {
+                case_key,
+                content_key,
+            } => emit_complex_enum::<T>(&case_key, &content_key),
+            EnumType::Untagged => emit_untagged_enum::<T>(),
+        })
+    }
+
+    fn emit_alias<T>(&mut self) -> String
+    where
+        T: AliasType,
+    {
+        with_option_style(self.option_style, || {
+            let source = to_jsdoc_type(&T::source_type());
+            typedef(T::name(), &source, &[], &T::docs())
+        })
+    }
+
+    fn finalize<P>(&mut self, _path: P) -> Result<(), std::io::Error>
+    where
+        P: AsRef<OsStr>,
+    {
+        Ok(())
+    }
+}
@@ -8,6 +8,7 @@ pub use std::io::Write;
 pub use std::path::Path;
 
 pub use ts_quote::ts_string;
+use ts_quote::{TSSource, TS};
 pub use type_reflect_macros::export_types;
 pub use type_reflect_macros::Reflect;
 pub mod struct_type;
@@ -21,6 +22,12 @@ pub mod enum_type;
 pub use enum_type::*;
 pub mod alias_type;
 pub use alias_type::*;
+pub mod type_overrides;
+pub use type_overrides::*;
+pub mod option_style;
+pub use option_style::*;
+pub mod definition;
+pub use definition::Definition;
 
 pub mod type_script;
 pub use type_script::TypeScript;
@@ -31,6 +38,15 @@ pub use ts_validation::TSValidation;
 pub mod ts_format;
 pub use ts_format::TSFormat;
 
+pub mod ts_transpile;
+pub use ts_transpile::TSTranspile;
+
+pub mod jsdoc;
+pub use jsdoc::JSDoc;
+
+#[cfg(feature = "testing")]
+pub mod testing;
+
 pub use serde::{Deserialize, Serialize};
 pub use serde_json;
 
@@ -42,6 +58,62 @@ pub use serde_json;
 /// will be generated by the `Reflect` derive macro.
 pub trait Emittable {
     fn emit_with<E: TypeEmitter>(emitter: &mut E) -> String;
+
+    /// The name the type is exported under, if it differs from the Rust name
+    ///
+    /// This is set with `#[reflect(export_name = "..")]`
+    fn export_name() -> Option<&'static str> {
+        None
+    }
+
+    /// The reflected definition of the type, which describes the shape of its values
+    fn definition() -> Option<Definition> {
+        None
+    }
+}
+
+/// Returns the unqualified type name of an emitter, i.e. `TypeScript`
+pub fn emitter_name<E: TypeEmitter>() -> &'static str {
+    let name = std::any::type_name::<E>();
+    let name = name.split('<').next().unwrap_or(name);
+    name.rsplit("::").next().unwrap_or(name)
+}
+
+/// Returns true if the emitter `E` passes the `emitters(..)` and
+/// `skip_emitters(..)` filters declared with `#[reflect(..)]`
+pub fn emitter_allowed<E: TypeEmitter>(emitters: Option<&[&str]>, skip_emitters: &[&str]) -> bool {
+    let name = emitter_name::<E>();
+    emitters.is_none_or(|emitters| emitters.contains(&name)) && !skip_emitters.contains(&name)
+}
+
+/// Checks the syntax of a `fragment` emitted by `E` from `source`, which is
+/// the Rust type or value the fragment was generated from
+///
+/// A syntax error is returned as an `InvalidData` error, so that the export fails
+/// before a broken file is written.
+pub fn check_fragment<E: TypeEmitter>(
+    emitter: &mut E,
+    source: &str,
+    fragment: &str,
+) -> Result<(), std::io::Error> {
+    emitter.check_syntax(fragment).map_err(|err| {
+        std::io::Error::new(
+            std::io::ErrorKind::InvalidData,
+            format!(
+                "{} emitted invalid syntax for `{source}`: {err}\n{fragment}",
+                emitter_name::<E>()
+            ),
+        )
+    })
+}
+
+/// Renders a serialized value of `type_`, such as a value declared in the `values`
+/// section of `export_types!`, as a TypeScript expression
+///
+/// The `null` members of `Option` fields which are emitted as optional are omitted,
+/// since those fields may be absent but are never `null`.
+pub fn json_literal(type_: &Type, value: serde_json::Value) -> String {
+    definition::omit_nulls(type_, value).to_string()
 }
 
 /// init_destination_file is called to generate the target file
@@ -146,6 +218,31 @@ pub trait TypeEmitter {
         T::emit_with::<Self>(self)
     }
 
+    /// Emits `T`, and checks the syntax of the result before it's written
+    /// to the destination file
+    fn emit_checked<T: Emittable>(&mut self) -> Result<String, std::io::Error>
+    where
+        Self: Sized,
+    {
+        let fragment = self.emit::<T>();
+        check_fragment(self, std::any::type_name::<T>(), &fragment)?;
+        Ok(fragment)
+    }
+
+    /// Checks the syntax of an emitted fragment, returning a description
+    /// of the syntax error if it can't be parsed
+    ///
+    /// By default fragments are parsed as TypeScript.  Emitters which
+    /// generate another language should override this.
+    fn check_syntax(&mut self, fragment: &str) -> Result<(), String> {
+        // The parser recovers from some errors, so those are reported as diagnostics
+        let parsed = TS::from_source(fragment.to_string()).map_err(|err| err.to_string())?;
+        match parsed.diagnostics().first() {
+            Some(diagnostic) => Err(diagnostic.to_string()),
+            None => Ok(()),
+        }
+    }
+
     /// Emit a struct representation from a struct type
     fn emit_struct<T>(&mut self) -> String
     where
@@ -160,6 +257,14 @@ pub trait TypeEmitter {
     fn emit_alias<T>(&mut self) -> String
     where
         T: AliasType;
+
+    /// Emit a named constant from a value declared in the `values`
+    /// section of `export_types!`
+    ///
+    /// By default values are not emitted.
+    fn emit_value(&mut self, _name: &str, _type_: &Type, _value: &serde_json::Value) -> String {
+        String::new()
+    }
 }
 
 pub trait RustType {
@@ -0,0 +1,25 @@
+use std::cell::Cell;
+
+use type_reflect_core::OptionStyle;
+
+thread_local! {
+    static OPTION_STYLE: Cell<OptionStyle> = const { Cell::new(OptionStyle::Optional) };
+}
+
+/// Runs `f` with `style` as the default style of `Option` fields on the current thread.
+///
+/// Emitters install their `option_style` while emitting a type, so the helpers which
+/// render nested types don't need to be passed the emitter.
+/// The previous style is restored once `f` returns.
+pub fn with_option_style<R>(style: OptionStyle, f: impl FnOnce() -> R) -> R {
+    let previous = OPTION_STYLE.with(|current| current.replace(style));
+    let result = f();
+    OPTION_STYLE.with(|current| current.set(previous));
+    result
+}
+
+/// Returns the style of a field with the given `#[reflect(option_style = "..")]`,
+/// falling back to the style installed by the emitter
+pub fn option_style(field_style: Option<OptionStyle>) -> OptionStyle {
+    field_style.unwrap_or_else(|| OPTION_STYLE.with(|current| current.get()))
+}
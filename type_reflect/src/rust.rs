@@ -35,6 +35,12 @@ impl TypeEmitter for Rust {
         format!("\n{}\n{}\n", DERIVES, T::rust())
     }
 
+    /// The output is copied from the source of the reflected types,
+    /// so it's not checked
+    fn check_syntax(&mut self, _fragment: &str) -> Result<(), String> {
+        Ok(())
+    }
+
     fn finalize<P>(&mut self, path: P) -> Result<(), std::io::Error>
     where
         P: AsRef<OsStr>,
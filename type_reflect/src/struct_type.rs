@@ -1,4 +1,7 @@
-use type_reflect_core::{type_description::TypeFieldsDefinition, Inflection};
+use type_reflect_core::{
+    type_description::{Documentation, Metadata, TypeFieldsDefinition},
+    Inflection,
+};
 
 /// A type implementing `StructType` can
 /// be used to emit a struct representation
@@ -7,4 +10,18 @@ pub trait StructType {
     fn inflection() -> Inflection;
     fn fields() -> TypeFieldsDefinition;
     fn rust() -> String;
+    fn docs() -> Documentation {
+        Documentation::default()
+    }
+    fn meta() -> Metadata {
+        Metadata::default()
+    }
+    /// The serialized `Default` value of the type, if it's declared with `#[reflect(default)]`
+    fn default_value() -> Option<serde_json::Value> {
+        None
+    }
+    /// Whether unknown fields are rejected, i.e. with `#[serde(deny_unknown_fields)]`
+    fn deny_unknown_fields() -> bool {
+        false
+    }
 }
@@ -0,0 +1,482 @@
+use std::collections::HashMap;
+use std::ffi::OsStr;
+
+use serde_json::{json, Map, Value};
+use type_reflect_core::{
+    Constraints, Direction, EnumCase, EnumType, Inflection, OptionStyle, StringFormat, Type,
+    TypeFieldsDefinition,
+};
+
+use super::exported_name;
+use crate::{
+    export_name, option_style, with_option_style, AliasType, Emittable, EnumReflectionType,
+    StructType, TypeEmitter,
+};
+
+/// The key added to objects to check how unknown fields are handled
+const UNKNOWN_FIELD: &str = "unknownField";
+/// The tag of an enum case which doesn't exist
+const UNKNOWN_CASE: &str = "UnknownCase";
+
+/// A xorshift generator, so that the generated values are reproducible from a seed
+struct Rng(u64);
+
+impl Rng {
+    fn new(seed: u64) -> Self {
+        // xorshift never leaves the zero state
+        Self((seed ^ 0x9E37_79B9_7F4A_7C15).max(1))
+    }
+
+    fn next(&mut self) -> u64 {
+        let mut x = self.0;
+        x ^= x << 13;
+        x ^= x >> 7;
+        x ^= x << 17;
+        self.0 = x;
+        x
+    }
+
+    /// A number in `min..=max`
+    fn between(&mut self, min: u64, max: u64) -> u64 {
+        match max.checked_sub(min) {
+            Some(range) if range < u64::MAX => min + self.next() % (range + 1),
+            _ => min,
+        }
+    }
+
+    /// True with the probability `p`
+    fn chance(&mut self, p: f64) -> bool {
+        ((self.next() >> 11) as f64 / (1u64 << 53) as f64) < p
+    }
+
+    fn pick<'a, T>(&mut self, items: &'a [T]) -> &'a T {
+        &items[self.between(0, items.len() as u64 - 1) as usize]
+    }
+}
+
+/// The reflected description of a type, as captured by `Describe`
+#[derive(Clone)]
+enum Definition {
+    Struct {
+        fields: TypeFieldsDefinition,
+        inflection: Inflection,
+    },
+    Enum {
+        cases: Vec<EnumCase>,
+        enum_type: EnumType,
+        inflection: Inflection,
+    },
+    Alias(Type),
+}
+
+/// An emitter which captures the description of the type it emits, rather than rendering it
+#[derive(Default)]
+struct Describe {
+    described: Option<(&'static str, Definition)>,
+}
+
+impl TypeEmitter for Describe {
+    fn prefix(&mut self) -> String {
+        String::new()
+    }
+
+    fn emit_struct<T>(&mut self) -> String
+    where
+        T: StructType,
+    {
+        let definition = Definition::Struct {
+            fields: T::fields().in_direction(Direction::Deserialize),
+            inflection: T::inflection(),
+        };
+        self.described = Some((T::name(), definition));
+        String::new()
+    }
+
+    fn emit_enum<T>(&mut self) -> String
+    where
+        T: EnumReflectionType,
+    {
+        let definition = Definition::Enum {
+            cases: T::cases(),
+            enum_type: T::enum_type(),
+            inflection: T::inflection(),
+        };
+        self.described = Some((T::name(), definition));
+        String::new()
+    }
+
+    fn emit_alias<T>(&mut self) -> String
+    where
+        T: AliasType,
+    {
+        self.described = Some((T::name(), Definition::Alias(T::source_type())));
+        String::new()
+    }
+
+    fn finalize<P>(&mut self, _path: P) -> Result<(), std::io::Error>
+    where
+        P: AsRef<OsStr>,
+    {
+        Ok(())
+    }
+}
+
+/// Generates random JSON values from the reflected descriptions of types
+///
+/// Values are generated as deserialization input, so some of them are invalid: with the
+/// probability `mutation_rate` a value is replaced by one of the wrong kind, a required field
+/// is dropped, an unknown field or enum case is introduced, or a tuple is cut short.
+///
+/// The types referenced by a generated type have to be registered as well.  Constraints are
+/// honored, except for `pattern`, since arbitrary regular expressions can't be generated from.
+pub struct Arbitrary {
+    rng: Rng,
+    definitions: HashMap<String, Definition>,
+    depth: usize,
+    /// The probability that a generated value is made invalid
+    pub mutation_rate: f64,
+    /// How `Option` fields are represented, which should match the emitters' `option_style`
+    pub option_style: OptionStyle,
+    /// The nesting depth past which optional values are omitted and collections are empty
+    pub max_depth: usize,
+}
+
+impl Arbitrary {
+    pub fn new(seed: u64) -> Self {
+        Self {
+            rng: Rng::new(seed),
+            definitions: HashMap::new(),
+            depth: 0,
+            mutation_rate: 0.1,
+            option_style: OptionStyle::Optional,
+            max_depth: 4,
+        }
+    }
+
+    pub fn with<T: Emittable>(mut self) -> Self {
+        self.register::<T>();
+        self
+    }
+
+    /// Registers the description of `T`, under its exported name as well as its Rust name
+    pub fn register<T: Emittable>(&mut self) {
+        let mut describe = Describe::default();
+        T::emit_with(&mut describe);
+        let (name, definition) = describe
+            .described
+            .unwrap_or_else(|| panic!("{} couldn't be described", exported_name::<T>()));
+        self.definitions
+            .insert(exported_name::<T>().to_string(), definition.clone());
+        self.definitions.insert(name.to_string(), definition);
+    }
+
+    /// Generates a value of the registered type with the given name
+    pub fn generate(&mut self, type_name: &str) -> Value {
+        with_option_style(self.option_style, || self.named(type_name))
+    }
+
+    fn named(&mut self, type_name: &str) -> Value {
+        let definition = self
+            .definitions
+            .get(type_name)
+            .or_else(|| self.definitions.get(&export_name(type_name)))
+            .cloned()
+            .unwrap_or_else(|| panic!("`{type_name}` isn't registered with the generator"));
+
+        self.depth += 1;
+        let value = match definition {
+            Definition::Struct { fields, inflection } => self.fields(&fields, inflection),
+            Definition::Enum {
+                cases,
+                enum_type,
+                inflection,
+            } => self.enum_value(&cases, &enum_type, inflection),
+            Definition::Alias(type_) => self.value(&type_),
+        };
+        self.depth -= 1;
+        value
+    }
+
+    fn mutate(&mut self) -> bool {
+        self.rng.chance(self.mutation_rate)
+    }
+
+    fn exhausted(&self) -> bool {
+        self.depth >= self.max_depth
+    }
+
+    fn value(&mut self, type_: &Type) -> Value {
+        match type_ {
+            Type::Named(named) => self.named(&named.name),
+            Type::Transparent(transparent) => self.value(&transparent.type_),
+            Type::Override { type_, .. } => self.value(type_),
+            Type::Option(inner) => match self.exhausted() || self.rng.chance(0.3) {
+                true => Value::Null,
+                false => self.value(inner),
+            },
+            Type::Inline(inline) => self.fields(&inline.fields, inline.inflection),
+            Type::Constrained { type_, constraints } => {
+                let value = self.constrained(type_, constraints);
+                self.wrong_kind_or(value)
+            }
+            type_ => {
+                let value = self.concrete(type_);
+                self.wrong_kind_or(value)
+            }
+        }
+    }
+
+    fn concrete(&mut self, type_: &Type) -> Value {
+        match type_ {
+            Type::String => json!(self.string(0, 8)),
+            Type::FormattedString(format) => json!(self.formatted_string(format)),
+            Type::Int => json!(self.rng.between(0, 200) as i64 - 100),
+            Type::UnsignedInt => json!(self.rng.between(0, 100)),
+            Type::Float => json!((self.rng.between(0, 20_000) as f64 - 10_000.0) / 100.0),
+            Type::Boolean => json!(self.rng.chance(0.5)),
+            Type::Null => Value::Null,
+            Type::Array(item) => {
+                let len = self.collection_len(0, 3);
+                Value::Array((0..len).map(|_| self.value(item)).collect())
+            }
+            Type::Map { key, value } => {
+                let len = self.collection_len(0, 3);
+                let mut map = Map::new();
+                for _ in 0..len {
+                    let key = self.key(key);
+                    map.insert(key, self.value(value));
+                }
+                Value::Object(map)
+            }
+            _ => unreachable!("wrapper types are unwrapped by `value`"),
+        }
+    }
+
+    /// Generates a map key, which is never mutated
+    fn key(&mut self, type_: &Type) -> String {
+        let mutation_rate = self.mutation_rate;
+        self.mutation_rate = 0.0;
+        let key = match self.value(type_) {
+            Value::String(key) => key,
+            key => key.to_string(),
+        };
+        self.mutation_rate = mutation_rate;
+        key
+    }
+
+    fn constrained(&mut self, type_: &Type, constraints: &Constraints) -> Value {
+        let min_len = constraints.min_len.unwrap_or(0);
+        let max_len = constraints.max_len.unwrap_or(min_len + 8);
+        match type_ {
+            Type::String if constraints.email => {
+                json!(format!("{}@example.com", self.string(1, 8)))
+            }
+            Type::String if constraints.url => {
+                json!(format!("https://example.com/{}", self.string(0, 8)))
+            }
+            Type::String => json!(self.string(min_len, max_len)),
+            Type::Int | Type::UnsignedInt => {
+                let min = constraints.min.map_or(0, |min| min.ceil() as i64);
+                let max = constraints.max.map_or(min + 100, |max| max.floor() as i64);
+                json!(min + self.rng.between(0, (max - min).max(0) as u64) as i64)
+            }
+            Type::Float => {
+                let min = constraints.min.unwrap_or(0.0);
+                let max = constraints.max.unwrap_or(min + 100.0);
+                let fraction = self.rng.between(0, 1000) as f64 / 1000.0;
+                json!(min + (max - min) * fraction)
+            }
+            Type::Array(item) => {
+                let len = self.collection_len(min_len, max_len.min(min_len + 3));
+                Value::Array((0..len).map(|_| self.value(item)).collect())
+            }
+            type_ => self.value(type_),
+        }
+    }
+
+    fn collection_len(&mut self, min: u64, max: u64) -> u64 {
+        match self.exhausted() {
+            true => min,
+            false => self.rng.between(min, max),
+        }
+    }
+
+    fn string(&mut self, min_len: u64, max_len: u64) -> String {
+        const CHARS: &[char] = &['a', 'b', 'z', 'A', 'Z', '0', '9', ' ', '_', '-', 'é', '"'];
+        let len = self.rng.between(min_len, max_len);
+        (0..len).map(|_| *self.rng.pick(CHARS)).collect()
+    }
+
+    fn formatted_string(&mut self, format: &StringFormat) -> String {
+        let date = format!(
+            "{}-{:02}-{:02}",
+            self.rng.between(1970, 2100),
+            self.rng.between(1, 12),
+            self.rng.between(1, 28)
+        );
+        let time = format!(
+            "{:02}:{:02}:{:02}",
+            self.rng.between(0, 23),
+            self.rng.between(0, 59),
+            self.rng.between(0, 59)
+        );
+        match format {
+            StringFormat::DateTime => format!("{date}T{time}Z"),
+            StringFormat::NaiveDateTime => format!("{date}T{time}"),
+            StringFormat::Date => date,
+            StringFormat::Time => time,
+            StringFormat::Uuid => {
+                let hex = format!("{:016x}{:016x}", self.rng.next(), self.rng.next());
+                format!(
+                    "{}-{}-{}-{}-{}",
+                    &hex[0..8],
+                    &hex[8..12],
+                    &hex[12..16],
+                    &hex[16..20],
+                    &hex[20..32]
+                )
+            }
+            StringFormat::Url => format!("https://example.com/{}", self.rng.between(0, 1000)),
+            StringFormat::Decimal => format!(
+                "{}.{:02}",
+                self.rng.between(0, 10_000),
+                self.rng.between(0, 99)
+            ),
+        }
+    }
+
+    /// Replaces the value by one of a different kind with the probability `mutation_rate`
+    ///
+    /// A number is never replaced by a number, i.e. an integer by a float, so the
+    /// replacement is rejected by any type which accepts the original value.
+    fn wrong_kind_or(&mut self, value: Value) -> Value {
+        if !self.mutate() {
+            return value;
+        }
+        match value {
+            Value::Object(_) | Value::String(_) => json!(7),
+            _ => json!("wrong"),
+        }
+    }
+
+    fn fields(&mut self, fields: &TypeFieldsDefinition, inflection: Inflection) -> Value {
+        match fields {
+            // A unit struct is serialized as `null`
+            TypeFieldsDefinition::Unit => self.wrong_kind_or(Value::Null),
+            // A newtype is serialized as its content
+            TypeFieldsDefinition::Tuple(items) if items.len() == 1 => self.value(&items[0]),
+            TypeFieldsDefinition::Tuple(items) => {
+                let mut values: Vec<Value> = items.iter().map(|item| self.value(item)).collect();
+                if self.mutate() {
+                    values.pop();
+                }
+                self.wrong_kind_or(Value::Array(values))
+            }
+            TypeFieldsDefinition::Named(fields) => {
+                let mut object = Map::new();
+                let mut required = vec![];
+                for field in fields {
+                    let name = field.serialized_name(inflection);
+                    match &field.type_ {
+                        Type::Option(inner) => {
+                            let style = option_style(field.option_style);
+                            let omitted = style.is_optional()
+                                && (self.exhausted() || self.rng.chance(0.3));
+                            if omitted {
+                                continue;
+                            }
+                            // serde writes `None` as `null` whatever the style
+                            let null = self.exhausted() || self.rng.chance(0.3);
+                            let value = match null {
+                                true => Value::Null,
+                                false => self.value(inner),
+                            };
+                            object.insert(name, value);
+                        }
+                        type_ => {
+                            let value = self.value(type_);
+                            object.insert(name.clone(), value);
+                            required.push(name);
+                        }
+                    }
+                }
+                if !required.is_empty() && self.mutate() {
+                    let name = self.rng.pick(&required).clone();
+                    object.remove(&name);
+                }
+                if self.mutate() {
+                    object.insert(UNKNOWN_FIELD.to_string(), json!(1));
+                }
+                self.wrong_kind_or(Value::Object(object))
+            }
+        }
+    }
+
+    fn enum_value(
+        &mut self,
+        cases: &[EnumCase],
+        enum_type: &EnumType,
+        inflection: Inflection,
+    ) -> Value {
+        if cases.is_empty() {
+            return self.wrong_kind_or(Value::Null);
+        }
+        let case = self.rng.pick(cases).clone();
+        let id = case.serialized_name(inflection);
+        // A fallback case accepts every unknown case
+        let unknown = !cases.iter().any(|case| case.fallback) && self.mutate();
+
+        let value = match enum_type {
+            EnumType::Simple => match unknown {
+                true => json!(UNKNOWN_CASE),
+                false => json!(id),
+            },
+            EnumType::Numeric => match unknown {
+                true => {
+                    let max = cases.iter().filter_map(|case| case.discriminant).max();
+                    json!(max.unwrap_or_default() + 1)
+                }
+                false => json!(case.discriminant.unwrap_or_default()),
+            },
+            EnumType::Complex {
+                case_key,
+                content_key,
+            } => {
+                let tag = match unknown {
+                    true => UNKNOWN_CASE.to_string(),
+                    false => id,
+                };
+                let mut object = Map::new();
+                match (&case.type_, content_key) {
+                    (TypeFieldsDefinition::Unit, _) => {}
+                    (fields, Some(content_key)) => {
+                        let content = self.fields(fields, case.inflection);
+                        object.insert(content_key.clone(), content);
+                    }
+                    // An internally tagged case merges its content with the tag
+                    (fields, None) => {
+                        if let Value::Object(content) = self.fields(fields, case.inflection) {
+                            object = content;
+                        }
+                    }
+                }
+                object.insert(case_key.clone(), json!(tag));
+                Value::Object(object)
+            }
+            EnumType::Untagged => match (&case.type_, unknown) {
+                (TypeFieldsDefinition::Unit, true) => json!(UNKNOWN_CASE),
+                (TypeFieldsDefinition::Unit, false) => json!(id),
+                (fields, unknown) => {
+                    let tag = match unknown {
+                        true => UNKNOWN_CASE.to_string(),
+                        false => id,
+                    };
+                    let mut object = Map::new();
+                    object.insert(tag, self.fields(fields, case.inflection));
+                    Value::Object(object)
+                }
+            },
+        };
+        self.wrong_kind_or(value)
+    }
+}
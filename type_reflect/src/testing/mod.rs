@@ -0,0 +1,182 @@
+//! Runs generated validators in an embedded JavaScript engine
+//!
+//! The generated TypeScript is transpiled in-process and evaluated with `boa_engine`,
+//! so tests of the generated code need neither node nor an installed `node_modules`.
+//! Only the output of the `TypeScript` and `TSValidation` emitters can be loaded,
+//! since destinations which import third-party packages, like `Zod`, can't be resolved.
+//!
+//! ```no_run
+//! # use type_reflect::*;
+//! # use type_reflect::testing::Runtime;
+//! # #[derive(Reflect, Serialize, Deserialize)]
+//! # struct User { name: String }
+//! let mut runtime = Runtime::load("./export/types.ts")?;
+//! runtime.assert_valid(&User { name: "Ada".to_string() });
+//! runtime.assert_invalid::<User>(r#"{ "name": 7 }"#);
+//! # Ok::<(), std::io::Error>(())
+//! ```
+
+use std::path::Path;
+
+use boa_engine::{
+    builtins::promise::PromiseState, Context, JsError, JsNativeError, JsResult, Module, Source,
+};
+use deno_ast::ModuleKind;
+use serde::{de::DeserializeOwned, Serialize};
+
+use crate::{ts_transpile, Emittable};
+
+mod arbitrary;
+pub use arbitrary::Arbitrary;
+
+/// A JavaScript context holding the exports of a generated TypeScript destination
+pub struct Runtime {
+    context: Context,
+}
+
+/// The name a type is exported under, i.e. `User` for `my_crate::models::User`
+fn exported_name<T: Emittable>() -> &'static str {
+    T::export_name().unwrap_or_else(|| {
+        let name = std::any::type_name::<T>();
+        let name = name.split('<').next().unwrap_or(name);
+        name.rsplit("::").next().unwrap_or(name)
+    })
+}
+
+impl Runtime {
+    /// Loads a destination generated by the `TypeScript` and `TSValidation` emitters
+    pub fn load<P: AsRef<Path>>(path: P) -> Result<Self, std::io::Error> {
+        Self::from_source(std::fs::read_to_string(path)?)
+    }
+
+    /// Loads generated TypeScript source
+    ///
+    /// The source is transpiled to an ES module and evaluated, and the exports of the
+    /// module are assigned to the global object, so they can be referenced by name
+    /// from `eval`.
+    pub fn from_source(source: String) -> Result<Self, std::io::Error> {
+        let script = ts_transpile::transpile(ts_transpile::parse(source)?, ModuleKind::Esm)?;
+        let mut context = Context::default();
+        Self::evaluate_module(&script, &mut context).map_err(|err| {
+            std::io::Error::new(
+                std::io::ErrorKind::InvalidData,
+                format!("Error evaluating generated code: {err}"),
+            )
+        })?;
+        Ok(Self { context })
+    }
+
+    fn evaluate_module(script: &str, context: &mut Context) -> JsResult<()> {
+        let module = Module::parse(Source::from_bytes(script), None, context)?;
+        let promise = module.load_link_evaluate(context);
+        context.run_jobs()?;
+        match promise.state() {
+            PromiseState::Fulfilled(_) => {}
+            PromiseState::Rejected(err) => return Err(JsError::from_opaque(err)),
+            PromiseState::Pending => {
+                return Err(JsNativeError::error()
+                    .with_message("the module didn't finish evaluating")
+                    .into())
+            }
+        }
+
+        let namespace = module.namespace(context);
+        let global = context.global_object();
+        for key in namespace.own_property_keys(context)? {
+            let value = namespace.get(key.clone(), context)?;
+            global.set(key, value, true, context)?;
+        }
+        Ok(())
+    }
+
+    /// Evaluates JavaScript source, returning its displayed result
+    /// or the message of the error it throws
+    pub fn eval(&mut self, source: &str) -> Result<String, String> {
+        self.context
+            .eval(Source::from_bytes(source))
+            .map(|value| value.display().to_string())
+            .map_err(|err| err.to_string())
+    }
+
+    /// Whether the named type has a generated `validate` function
+    pub fn has_validator(&mut self, type_name: &str) -> bool {
+        self.eval(&format!(
+            r#"typeof {type_name} !== "undefined" && typeof {type_name}.validate === "function""#
+        ))
+        .is_ok_and(|result| result == "true")
+    }
+
+    /// Validates a value with the `validate` function of the named type,
+    /// returning the validation error if it's rejected
+    pub fn validate(&mut self, type_name: &str, value: &serde_json::Value) -> Result<(), String> {
+        self.eval(&format!("{type_name}.validate({value}); undefined"))
+            .map(|_| ())
+    }
+
+    /// Asserts that the serialized `value` passes the validation of its type
+    #[track_caller]
+    pub fn assert_valid<T: Emittable + Serialize>(&mut self, value: &T) {
+        let name = exported_name::<T>();
+        assert!(self.has_validator(name), "{name} has no generated validator");
+        let value = serde_json::to_value(value).expect("the value should serialize");
+        if let Err(err) = self.validate(name, &value) {
+            panic!("{value} was rejected by {name}.validate: {err}");
+        }
+    }
+
+    /// Asserts that the hand-written `json` fails the validation of `T`
+    #[track_caller]
+    pub fn assert_invalid<T: Emittable>(&mut self, json: &str) {
+        let name = exported_name::<T>();
+        assert!(self.has_validator(name), "{name} has no generated validator");
+        let value: serde_json::Value = serde_json::from_str(json)
+            .unwrap_or_else(|err| panic!("`{json}` isn't valid JSON: {err}"));
+        if self.validate(name, &value).is_ok() {
+            panic!("{value} was accepted by {name}.validate");
+        }
+    }
+
+    /// Checks that serde and the generated validator of `T` accept and reject the same
+    /// `count` values generated by `arbitrary`, and that the validator accepts the
+    /// serialization of every value serde accepts
+    ///
+    /// Returns what was exercised, so a test can check that every case of `T` was covered.
+    #[track_caller]
+    pub fn assert_round_trip<T>(&mut self, arbitrary: &mut Arbitrary, count: usize) -> RoundTrip<T>
+    where
+        T: Emittable + Serialize + DeserializeOwned,
+    {
+        let name = exported_name::<T>();
+        assert!(self.has_validator(name), "{name} has no generated validator");
+        let mut round_trip = RoundTrip {
+            accepted: vec![],
+            rejected: 0,
+        };
+        for _ in 0..count {
+            let input = arbitrary.generate(name);
+            let deserialized = serde_json::from_value::<T>(input.clone());
+            match (deserialized, self.validate(name, &input)) {
+                (Ok(value), Ok(())) => {
+                    self.assert_valid(&value);
+                    round_trip.accepted.push(value);
+                }
+                (Err(_), Err(_)) => round_trip.rejected += 1,
+                (Ok(_), Err(err)) => {
+                    panic!("{input} deserializes as {name}, but was rejected by {name}.validate: {err}")
+                }
+                (Err(err), Ok(())) => {
+                    panic!("{input} was accepted by {name}.validate, but doesn't deserialize: {err}")
+                }
+            }
+        }
+        round_trip
+    }
+}
+
+/// The values exercised by `Runtime::assert_round_trip`
+pub struct RoundTrip<T> {
+    /// The values accepted by both serde and the generated validator
+    pub accepted: Vec<T>,
+    /// How many generated values were rejected by both
+    pub rejected: usize,
+}
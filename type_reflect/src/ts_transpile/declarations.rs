@@ -0,0 +1,145 @@
+use deno_ast::{
+    swc::{
+        ast::{
+            ClassMember, Decl, Expr, Lit, Module, ModuleDecl, ModuleItem, Pat, Program, Prop,
+            PropName, PropOrSpread, Stmt, TsNamespaceBody, TsTypeAnn, VarDecl,
+        },
+        codegen::{text_writer::JsWriter, Config, Emitter},
+        common::{sync::Lrc, SourceMap},
+    },
+    ParsedSource,
+};
+
+use super::parse;
+
+/// Emits the declarations of a parsed TypeScript module, i.e. the contents of its `.d.ts` file
+///
+/// The implementations of functions, classes and constants are removed, and the
+/// statements which aren't exported are dropped, except for type declarations.
+pub fn emit_declarations(parsed: &ParsedSource) -> Result<String, std::io::Error> {
+    let mut module: Module = match parsed.program().as_ref() {
+        Program::Module(module) => module.clone(),
+        Program::Script(_) => return Err(std::io::Error::other("expected a module")),
+    };
+    declare_items(&mut module.body, false);
+
+    let source_map: Lrc<SourceMap> = Default::default();
+    let mut output = vec![];
+    {
+        let mut emitter = Emitter {
+            cfg: Config::default(),
+            cm: source_map.clone(),
+            comments: None,
+            wr: JsWriter::new(source_map, "\n", &mut output, None),
+        };
+        emitter.emit_module(&module)?;
+    }
+    String::from_utf8(output).map_err(std::io::Error::other)
+}
+
+/// Replaces the items with their declarations
+///
+/// Inside a namespace the items are already ambient, so they aren't marked `declare`.
+fn declare_items(items: &mut Vec<ModuleItem>, ambient: bool) {
+    items.retain_mut(|item| match item {
+        ModuleItem::ModuleDecl(ModuleDecl::ExportDecl(export)) => {
+            declare(&mut export.decl, ambient);
+            true
+        }
+        ModuleItem::ModuleDecl(ModuleDecl::Import(_)) => true,
+        ModuleItem::ModuleDecl(_) => false,
+        ModuleItem::Stmt(Stmt::Decl(Decl::TsTypeAlias(_) | Decl::TsInterface(_))) => true,
+        ModuleItem::Stmt(_) => false,
+    });
+}
+
+fn declare(decl: &mut Decl, ambient: bool) {
+    match decl {
+        Decl::Fn(fn_decl) => {
+            fn_decl.declare = !ambient;
+            fn_decl.function.body = None;
+        }
+        Decl::Class(class_decl) => {
+            class_decl.declare = !ambient;
+            for member in class_decl.class.body.iter_mut() {
+                match member {
+                    ClassMember::Constructor(constructor) => constructor.body = None,
+                    ClassMember::Method(method) => method.function.body = None,
+                    ClassMember::ClassProp(prop) => prop.value = None,
+                    _ => {}
+                }
+            }
+        }
+        Decl::Var(var_decl) => declare_var(var_decl, ambient),
+        Decl::TsEnum(enum_decl) => enum_decl.declare = !ambient,
+        Decl::TsModule(module_decl) => {
+            module_decl.declare = !ambient;
+            if let Some(TsNamespaceBody::TsModuleBlock(block)) = &mut module_decl.body {
+                declare_items(&mut block.body, true);
+            }
+        }
+        _ => {}
+    }
+}
+
+/// Removes the initializers of the variables, which are typed by their
+/// initializer if they don't have a type annotation
+fn declare_var(var_decl: &mut VarDecl, ambient: bool) {
+    var_decl.declare = !ambient;
+    for declarator in var_decl.decls.iter_mut() {
+        let init = declarator.init.take();
+        if let Pat::Ident(binding) = &mut declarator.name
+            && binding.type_ann.is_none()
+        {
+            let type_ = init.as_deref().map(inferred_type);
+            binding.type_ann = type_annotation(type_.as_deref().unwrap_or("any"));
+        }
+    }
+}
+
+/// Returns the type TypeScript infers for a literal initializer, or `any` otherwise
+fn inferred_type(expr: &Expr) -> String {
+    match expr {
+        Expr::Lit(Lit::Str(_)) | Expr::Tpl(_) => "string".to_string(),
+        Expr::Lit(Lit::Num(_)) => "number".to_string(),
+        Expr::Lit(Lit::Bool(_)) => "boolean".to_string(),
+        Expr::Object(object) => {
+            let mut members = vec![];
+            for prop in &object.props {
+                match prop {
+                    PropOrSpread::Prop(prop) => match prop.as_ref() {
+                        Prop::KeyValue(key_value) => match &key_value.key {
+                            PropName::Ident(key) => members.push(format!(
+                                "{}: {}",
+                                key.sym,
+                                inferred_type(&key_value.value)
+                            )),
+                            _ => return "any".to_string(),
+                        },
+                        _ => return "any".to_string(),
+                    },
+                    PropOrSpread::Spread(_) => return "any".to_string(),
+                }
+            }
+            format!("{{ {} }}", members.join("; "))
+        }
+        _ => "any".to_string(),
+    }
+}
+
+/// Parses a type annotation from its source
+fn type_annotation(type_: &str) -> Option<Box<TsTypeAnn>> {
+    let parsed = parse(format!("let value: {type_};")).ok()?;
+    match parsed.program().as_ref() {
+        Program::Module(module) => match module.body.first() {
+            Some(ModuleItem::Stmt(Stmt::Decl(Decl::Var(var_decl)))) => {
+                match &var_decl.decls.first()?.name {
+                    Pat::Ident(binding) => binding.type_ann.clone(),
+                    _ => None,
+                }
+            }
+            _ => None,
+        },
+        Program::Script(_) => None,
+    }
+}
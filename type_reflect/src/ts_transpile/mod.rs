@@ -0,0 +1,129 @@
+use std::{ffi::OsStr, path::Path};
+
+use deno_ast::{
+    parse_module, EmitOptions, MediaType, ModuleKind, ModuleSpecifier, ParseParams, ParsedSource,
+    SourceMapOption, TranspileModuleOptions, TranspileOptions,
+};
+use dprint_plugin_typescript::{configuration::ConfigurationBuilder, FormatTextOptions};
+
+use crate::{AliasType, EnumReflectionType, StructType, TypeEmitter};
+
+mod declarations;
+use declarations::emit_declarations;
+
+/// Transpiles the TypeScript destination to an ES module, i.e. `types.ts` to `types.js`,
+/// along with a declaration file `types.d.ts`
+///
+/// Like `TSFormat`, this works on the output of the preceding emitters, so it should be
+/// listed after them.  The transpilation happens in-process, so neither `tsc` nor node
+/// are required.
+#[derive(Default)]
+pub struct TSTranspile {}
+
+pub(crate) fn parse(text: String) -> Result<ParsedSource, std::io::Error> {
+    parse_module(ParseParams {
+        specifier: ModuleSpecifier::parse("file:///types.ts").unwrap(),
+        text: text.into(),
+        media_type: MediaType::TypeScript,
+        capture_tokens: false,
+        scope_analysis: false,
+        maybe_syntax: None,
+    })
+    .map_err(|err| err.to_string())
+    .and_then(|parsed| match parsed.diagnostics().first() {
+        // The parser recovers from some errors, which must not be transpiled as if valid
+        Some(diagnostic) => Err(diagnostic.to_string()),
+        None => Ok(parsed),
+    })
+    .map_err(|err| std::io::Error::other(format!("Error parsing typescript: {err}")))
+}
+
+/// Transpiles parsed TypeScript to JavaScript, as an ES module or as CommonJS
+pub(crate) fn transpile(
+    parsed: ParsedSource,
+    module_kind: ModuleKind,
+) -> Result<String, std::io::Error> {
+    let emit_options = EmitOptions {
+        source_map: SourceMapOption::None,
+        ..Default::default()
+    };
+    let transpiled = parsed
+        .transpile(
+            &TranspileOptions::default(),
+            &TranspileModuleOptions {
+                module_kind: Some(module_kind),
+            },
+            &emit_options,
+        )
+        .map_err(|err| std::io::Error::other(format!("Error transpiling typescript: {err}")))?;
+    Ok(transpiled.into_source().text)
+}
+
+fn format_declarations(path: &Path, text: String) -> String {
+    let config = ConfigurationBuilder::new()
+        .indent_width(2)
+        .line_width(80)
+        .build();
+    let options = FormatTextOptions {
+        path,
+        extension: None,
+        text: text.clone(),
+        config: &config,
+        external_formatter: None,
+    };
+    match dprint_plugin_typescript::format_text(options) {
+        Ok(Some(contents)) => contents,
+        Ok(None) => text,
+        Err(err) => {
+            eprintln!("Error formatting declarations: {}", err);
+            text
+        }
+    }
+}
+
+impl TypeEmitter for TSTranspile {
+    fn prefix(&mut self) -> String {
+        "".to_string()
+    }
+
+    fn emit_struct<T>(&mut self) -> String
+    where
+        T: StructType,
+    {
+        "".to_string()
+    }
+
+    fn emit_enum<T>(&mut self) -> String
+    where
+        T: EnumReflectionType,
+    {
+        "".to_string()
+    }
+
+    fn emit_alias<T>(&mut self) -> String
+    where
+        T: AliasType,
+    {
+        "".to_string()
+    }
+
+    fn finalize<P>(&mut self, path: P) -> Result<(), std::io::Error>
+    where
+        P: AsRef<OsStr>,
+    {
+        let path = Path::new(&path);
+        let text: String = std::fs::read_to_string(path)?;
+        let parsed = parse(text)?;
+
+        let declarations_path = path.with_extension("d.ts");
+        let declarations = emit_declarations(&parsed)?;
+        std::fs::write(
+            &declarations_path,
+            format_declarations(&declarations_path, declarations),
+        )?;
+
+        std::fs::write(path.with_extension("js"), transpile(parsed, ModuleKind::Esm)?)?;
+
+        Ok(())
+    }
+}
@@ -0,0 +1,20 @@
+use ts_quote::ts_string;
+
+use super::{validation::type_validation, validation_namespace};
+use crate::AliasType;
+
+pub fn emit_alias_type<T>() -> String
+where
+    T: AliasType,
+{
+    let name = T::name();
+    let validation = type_validation("input", "path", &T::source_type());
+    validation_namespace(
+        name,
+        ts_string! {
+            #validation
+            return input as #name;
+        }
+        .as_str(),
+    )
+}
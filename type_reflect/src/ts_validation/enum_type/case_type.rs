@@ -1,8 +1,12 @@
 use ts_quote::ts_string;
-use type_reflect_core::{EnumCase, Inflection, NamedField, Type};
+use type_reflect_core::{EnumCase, Inflection, NamedField, Type, TypeFieldsDefinition};
 
 use crate::ts_validation::{
-    struct_type::named_field_validations, validation::tuple_validation, validation_namespace,
+    struct_type::named_field_validations,
+    validation::{
+        field_path, push_error, tuple_validation, type_validation, unknown_fields_validation,
+    },
+    validation_namespace,
 };
 
 pub fn emit_complex_enum_case_type(
@@ -10,6 +14,7 @@ pub fn emit_complex_enum_case_type(
     case_key: &String,
     content_key: &Option<String>,
     case: EnumCase,
+    strict: bool,
 ) -> String {
     let case_key_value: String = format!("{}CaseKey.{}", enum_name, case.name);
     let case_type_name: String = format!("{}Case{}", enum_name, case.name);
@@ -17,42 +22,74 @@ pub fn emit_complex_enum_case_type(
     let validator = match &case.type_ {
         type_reflect_core::TypeFieldsDefinition::Unit => emit_simple_case_type_validator(),
         type_reflect_core::TypeFieldsDefinition::Tuple(members) => {
-            emit_tuple_case_type_validator(content_key, members)
+            emit_tuple_case_type_validator(case_key, content_key, members)
         }
         type_reflect_core::TypeFieldsDefinition::Named(members) => {
-            emit_struct_case_type_validator(content_key, members, case.inflection)
+            emit_struct_case_type_validator(content_key, members, case.inflection, strict)
         }
     };
+    let validator = match strict {
+        true => format!(
+            "{}{}",
+            emit_unknown_fields_validator(case_key, content_key, &case),
+            validator
+        ),
+        false => validator,
+    };
 
-    let validation_impl = match &case.type_ {
-        type_reflect_core::TypeFieldsDefinition::Unit => {
-            ts_string! {
-                if (!isRecord(input)) {
-                    throw new Error(# "`Error parsing #case_type_name: expected: Record, found: ${typeof input}`");
-                }
-                if (input.#case_key !== #case_key_value) {
-                    throw new Error(# "`Error parsing #case_type_name: expected key: #case_key_value, found: ${typeof input}`");
-                }
-                return input as #case_type_name
-            }
+    // The tag isn't always a valid identifier, i.e. with `#[serde(tag = "@type")]`
+    let tag = serde_json::to_string(case_key).expect("a string always serializes");
+    let record_error = push_error("path", "`expected: Record, found: ${typeof input}`");
+    let key_error = push_error(
+        &field_path("path", case_key),
+        &format!("`expected key: ${{{case_key_value}}}, found: ${{JSON.stringify(input[{tag}])}}`"),
+    );
+
+    let validation_impl = ts_string! {
+        if (!isRecord(input)) {
+            #record_error
+            return input as #case_type_name;
         }
-        _ => {
-            ts_string! {
-                if (!isRecord(input)) {
-                    throw new Error(# "`Error parsing #case_type_name: expected: Record, found: ${typeof input}`");
-                }
-                if (input.#case_key !== #case_key_value) {
-                    throw new Error(# "`Error parsing #case_type_name: expected key: #case_key_value, found: ${typeof input}`");
-                }
-                #validator
-                return input as #case_type_name
-            }
+        if (input[#tag] !== #case_key_value) {
+            #key_error
+            return input as #case_type_name;
         }
+        #validator
+        return input as #case_type_name
     };
 
     validation_namespace(case_type_name.as_str(), validation_impl.as_str())
 }
 
+/// Rejects the keys of the input which aren't the tag, the content or a field of the variant
+///
+/// The fields of adjacently tagged content are checked along with its type.
+fn emit_unknown_fields_validator(
+    case_key: &str,
+    content_key: &Option<String>,
+    case: &EnumCase,
+) -> String {
+    match content_key {
+        Some(content_key) => {
+            let keys = match &case.type_ {
+                TypeFieldsDefinition::Unit => vec![case_key],
+                _ => vec![case_key, content_key.as_str()],
+            };
+            unknown_fields_validation("input", "path", &[], case.inflection, &keys)
+        }
+        // The fields of an internally tagged newtype variant are checked by its content type
+        None => match &case.type_ {
+            TypeFieldsDefinition::Tuple(_) => String::new(),
+            TypeFieldsDefinition::Named(fields) => {
+                unknown_fields_validation("input", "path", fields, case.inflection, &[case_key])
+            }
+            TypeFieldsDefinition::Unit => {
+                unknown_fields_validation("input", "path", &[], case.inflection, &[case_key])
+            }
+        },
+    }
+}
+
 fn emit_simple_case_type_validator() -> String {
     String::new()
 }
@@ -61,18 +98,65 @@ fn emit_struct_case_type_validator(
     content_key: &Option<String>,
     members: &Vec<NamedField>,
     inflection: Inflection,
+    strict: bool,
 ) -> String {
-    let member_prefix = match content_key {
-        None => "input".to_string(),
-        Some(key) => format!("input.{}", key),
-    };
-    named_field_validations(member_prefix.as_str(), members, inflection)
+    match content_key {
+        None => named_field_validations("input", "path", members, inflection),
+        Some(key) => {
+            let member_prefix = content_var(key);
+            let path = field_path("path", key);
+            let validations = named_field_validations(&member_prefix, &path, members, inflection);
+            let unknown_fields = match strict {
+                true => unknown_fields_validation(&member_prefix, &path, members, inflection, &[]),
+                false => String::new(),
+            };
+            let error = push_error(
+                &path,
+                &format!("`expected: Record, found: ${{typeof {member_prefix}}}`"),
+            );
+            format!(
+                r#"
+    if (!isRecord({member_prefix})) {{
+        {error}
+    }} else {{
+        {unknown_fields}
+        {validations}
+    }}
+    "#
+            )
+        }
+    }
 }
 
-fn emit_tuple_case_type_validator(content_key: &Option<String>, members: &Vec<Type>) -> String {
-    let member_prefix = match content_key {
-        None => "input".to_string(),
-        Some(key) => format!("input.{}", key),
-    };
-    tuple_validation(member_prefix.as_str(), members)
-}
\ No newline at end of file
+fn emit_tuple_case_type_validator(
+    case_key: &str,
+    content_key: &Option<String>,
+    members: &Vec<Type>,
+) -> String {
+    match content_key {
+        // An internally tagged newtype variant merges the fields of its content with the tag,
+        // so the content is validated from a copy of the input without the tag
+        None if members.len() == 1 => {
+            let case_key = serde_json::to_string(case_key).expect("a string always serializes");
+            let validation = type_validation("content", "path", &members[0]);
+            format!(
+                r#"
+    {{
+        const {{ [{case_key}]: _, ...content }} = input;
+        {validation}
+    }}
+    "#
+            )
+        }
+        None => tuple_validation("input", "path", members),
+        Some(key) => tuple_validation(&content_var(key), &field_path("path", key), members),
+    }
+}
+
+/// The content of an adjacently tagged value, whose key isn't always a valid identifier
+fn content_var(content_key: &str) -> String {
+    format!(
+        "input[{}]",
+        serde_json::to_string(content_key).expect("a string always serializes")
+    )
+}
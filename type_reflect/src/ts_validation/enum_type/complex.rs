@@ -1,17 +1,29 @@
 use type_reflect_core::EnumCase;
 
-use crate::{ts_validation::validation_namespace, EnumReflectionType};
+use crate::{
+    ts_validation::{
+        validation::{field_path, push_error},
+        validation_namespace_with_members,
+    },
+    EnumReflectionType,
+};
 
 use super::case_type::emit_complex_enum_case_type;
 use ts_quote::ts_string;
 
-pub fn emit_complex_enum_type<T>(case_key: &String, content_key: &Option<String>) -> String
+pub fn emit_complex_enum_type<T>(
+    case_key: &String,
+    content_key: &Option<String>,
+    strict: bool,
+) -> String
 where
     T: EnumReflectionType,
 {
     let case_type_validators: String = T::cases()
         .into_iter()
-        .map(|case: EnumCase| emit_complex_enum_case_type(T::name(), case_key, content_key, case))
+        .map(|case: EnumCase| {
+            emit_complex_enum_case_type(T::name(), case_key, content_key, case, strict)
+        })
         .collect();
 
     let case_validations: String = T::cases()
@@ -19,12 +31,51 @@ where
         .map(|case: EnumCase| validate_case(T::name(), &case))
         .collect();
 
+    // The tag isn't always a valid identifier, i.e. with `#[serde(tag = "@type")]`
+    let tag = serde_json::to_string(case_key).expect("a string always serializes");
+    let fallback = match T::cases().into_iter().find(|case| case.fallback) {
+        Some(case) => validate_fallback(T::name(), &tag, &case),
+        None => String::new(),
+    };
+
     let name = T::name();
 
-    let namespace = validation_namespace(T::name(), ts_string! {
-        #case_validations
-        throw new Error(# "`Error validating #name: value ${JSON.stringify(input)} does not match any variant`");
-    }.as_str());
+    let tags: Vec<String> = T::cases()
+        .iter()
+        .map(|case| case.serialized_name(T::inflection()))
+        .collect();
+    let tags = serde_json::to_string(&tags.join(", ")).expect("a string always serializes");
+    let record_error = push_error("path", "`expected: Record, found: ${typeof input}`");
+    let tag_error = push_error(
+        &field_path("path", case_key),
+        &format!("`unknown tag: ${{JSON.stringify(input[{tag}])}}, expected one of: ` + {tags}"),
+    );
+
+    let predicates: String = T::cases()
+        .iter()
+        .map(|case| case_predicate(name, &tag, case))
+        .collect();
+
+    // Only the case matching the tag is validated, so its errors are reported
+    let namespace = validation_namespace_with_members(
+        T::name(),
+        &format!(
+            r#"
+if (!isRecord(input)) {{
+    {record_error}
+    return input as {name};
+}}
+switch (input[{tag}]) {{
+    {case_validations}
+    default:
+        {fallback}
+        {tag_error}
+        return input as {name};
+}}
+"#
+        ),
+        &predicates,
+    );
 
     ts_string! {
         #case_type_validators
@@ -34,10 +85,38 @@ where
 
 fn validate_case(type_name: &str, case: &EnumCase) -> String {
     let case_type = format!("{}Case{}", type_name, case.name);
+    let case_key = format!("{}CaseKey.{}", type_name, case.name);
+
+    format!(
+        r#"
+    case {case_key}:
+        return {case_type}.validateAt(input, path, errors);"#
+    )
+}
+
+/// Emits a predicate which narrows a value of the enum to the case, e.g. `Status.isInProgress(status)`
+///
+/// The `tag` is the case key as a string literal.
+fn case_predicate(type_name: &str, tag: &str, case: &EnumCase) -> String {
+    format!(
+        r#"
+    export function is{case}(value: {type_name}): value is {type_name}Case{case} {{
+        return value[{tag}] === {type_name}CaseKey.{case};
+    }}
+"#,
+        case = case.name
+    )
+}
+
+/// Validates an unrecognized variant as the fallback case, discarding its content like serde does
+///
+/// The `tag` is the case key as a string literal.
+fn validate_fallback(type_name: &str, tag: &str, case: &EnumCase) -> String {
+    let fallback_key = format!("{}CaseKey.{}", type_name, case.name);
 
     ts_string! {
-        try {
-            return #case_type.validate(input);
-        } catch {}
+        if (typeof input[#tag] === "string") {
+            return { #tag: #fallback_key } as #type_name;
+        }
     }
-}
\ No newline at end of file
+}
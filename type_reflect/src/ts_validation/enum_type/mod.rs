@@ -1,7 +1,10 @@
 use type_reflect_core::EnumType;
 use untagged::emit_untagged_enum_type;
 
-use crate::{ts_validation::validation_namespace, EnumReflectionType};
+use crate::{
+    ts_validation::{validation::push_error, validation_namespace},
+    EnumReflectionType,
+};
 
 mod complex;
 use complex::*;
@@ -10,17 +13,19 @@ mod case_type;
 
 mod untagged;
 
-pub fn emit_enum_type<T>() -> String
+/// Emits the validation of an enum, which rejects unknown fields of its variants if `strict` is set
+pub fn emit_enum_type<T>(strict: bool) -> String
 where
     T: EnumReflectionType,
 {
     match T::enum_type() {
         EnumType::Simple => emit_simple_enum_type::<T>(),
+        EnumType::Numeric => emit_numeric_enum_type::<T>(),
         EnumType::Complex {
             case_key,
             content_key,
-        } => emit_complex_enum_type::<T>(&case_key, &content_key),
-        EnumType::Untagged => emit_untagged_enum_type::<T>(),
+        } => emit_complex_enum_type::<T>(&case_key, &content_key, strict),
+        EnumType::Untagged => emit_untagged_enum_type::<T>(strict),
     }
 }
 
@@ -28,12 +33,57 @@ fn emit_simple_enum_type<T>() -> String
 where
     T: EnumReflectionType,
 {
+    let fallback = match T::cases().into_iter().find(|case| case.fallback) {
+        Some(case) => format!(
+            r#"
+if(typeof input === 'string') {{
+    return {name}.{case};
+}}"#,
+            name = T::name(),
+            case = case.name
+        ),
+        None => String::new(),
+    };
+    let error = push_error(
+        "path",
+        &format!(
+            "`expected one of: ${{Object.values({name}).join(', ')}}, found: ${{JSON.stringify(input)}}`",
+            name = T::name()
+        ),
+    );
     let validation_impl = format!(
         r#"
 if(Object.values({name}).includes(input as {name})) {{
     return input as {name};
+}}{fallback}
+{error}
+return input as {name};
+"#,
+        name = T::name(),
+    );
+    validation_namespace(T::name(), validation_impl.as_str())
+}
+
+fn emit_numeric_enum_type<T>() -> String
+where
+    T: EnumReflectionType,
+{
+    // Numeric enums also map their names to their values, so the type is checked
+    // to exclude the names
+    let error = push_error(
+        "path",
+        &format!(
+            "`expected a value of {name}, found: ${{JSON.stringify(input)}}`",
+            name = T::name()
+        ),
+    );
+    let validation_impl = format!(
+        r#"
+if(typeof input === 'number' && Object.values({name}).includes(input)) {{
+    return input as {name};
 }}
-throw new Error(`Error parsing {name}: value does not conform: ${{JSON.stringify(input)}}`)
+{error}
+return input as {name};
 "#,
         name = T::name(),
     );
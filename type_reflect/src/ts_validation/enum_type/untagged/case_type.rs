@@ -2,10 +2,12 @@ use ts_quote::ts_string;
 use type_reflect_core::EnumCase;
 
 use crate::ts_validation::{
-    struct_type::named_field_validations, validation::tuple_validation, validation_namespace,
+    struct_type::named_field_validations,
+    validation::{push_error, tuple_validation, unknown_fields_validation},
+    validation_namespace,
 };
 
-pub fn emit_case_type(case: &EnumCase, parent_name: &str) -> String {
+pub fn emit_case_type(case: &EnumCase, parent_name: &str, strict: bool) -> String {
     let case_type = format!("{}Case{}", parent_name, case.name);
     let validation_impl = match &case.type_ {
         type_reflect_core::TypeFieldsDefinition::Unit => {
@@ -15,14 +17,21 @@ pub fn emit_case_type(case: &EnumCase, parent_name: &str) -> String {
             if members.len() == 1 {
                 return "".to_string();
             }
-            tuple_validation("input", members)
+            tuple_validation("input", "path", members)
         }
         type_reflect_core::TypeFieldsDefinition::Named(fields) => {
-            let val = named_field_validations("input", fields, case.inflection);
+            let val = named_field_validations("input", "path", fields, case.inflection);
+            let unknown_fields = match strict {
+                true => unknown_fields_validation("input", "path", fields, case.inflection, &[]),
+                false => String::new(),
+            };
+            let error = push_error("path", "`expected: Record, found: ${typeof input}`");
             ts_string! {
                 if (!isRecord(input)) {
-                    throw new Error(# "`Error parsing #case_type: expected: Record, found: ${typeof input}`");
+                    #error
+                    return input as #case_type;
                 }
+                #unknown_fields
                 #val
             }
         }
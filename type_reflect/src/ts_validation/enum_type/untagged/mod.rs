@@ -1,15 +1,18 @@
 use case_type::emit_case_type;
 use ts_quote::ts_string;
-use type_reflect_core::{EnumCase, Inflectable, TypeFieldsDefinition};
+use type_reflect_core::{EnumCase, TypeFieldsDefinition};
 use union_case::union_case_validation;
 use unit_case::unit_case_validation;
 
-use crate::{ts_validation::validation_namespace, EnumReflectionType};
+use crate::{
+    ts_validation::{validation::push_error, validation_namespace},
+    EnumReflectionType,
+};
 mod case_type;
 mod union_case;
 mod unit_case;
 
-pub fn emit_untagged_enum_type<T>() -> String
+pub fn emit_untagged_enum_type<T>(strict: bool) -> String
 where
     T: EnumReflectionType,
 {
@@ -28,7 +31,7 @@ where
         })
         .map(|case| {
             
-            case.name.inflect(inflection)
+            case.serialized_name(inflection)
         })
         .collect();
 
@@ -40,10 +43,12 @@ where
             .map(|case_name| unit_case_validation(case_name.as_str(), name))
             .collect();
         let unit_case_validations = unit_case_validations.join("\n");
+        let error = push_error("path", "`none of the unit cases were matched`");
         ts_string! {
             if (# "'string'" === typeof input) {
                 #unit_case_validations
-                throw new Error(# "`Error validating #name: none of the unit cases were matched`");
+                #error
+                return input as #name;
             }
         }
     };
@@ -67,9 +72,11 @@ where
             .map(|case| union_case_validation(case, name, inflection))
             .collect();
         let union_case_validations = union_case_validations.join("\n");
+        let error = push_error("path", "`expected: Record, found: ${typeof input}`");
         ts_string! {
             if (!isRecord(input)) {
-                throw new Error(# "`Error parsing #name: expected: Record, found: ${typeof input}`");
+                #error
+                return input as #name;
             }
             #union_case_validations
         }
@@ -77,17 +84,19 @@ where
 
     let union_case_types: Vec<_> = union_cases
         .iter()
-        .map(|case| emit_case_type(case, name))
+        .map(|case| emit_case_type(case, name, strict))
         .collect();
 
     let union_case_types = union_case_types.join("\n");
 
+    let error = push_error("path", "`none of the union cases were matched`");
     let namespace = validation_namespace(
         name,
         &ts_string! {
             #unit_case_validations
             #union_case_validations
-            throw new Error(# "`Error validating #name: none of the union cases were matched`");
+            #error
+            return input as #name;
         },
     );
 
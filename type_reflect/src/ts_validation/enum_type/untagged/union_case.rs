@@ -1,13 +1,13 @@
 use ts_quote::ts_string;
-use type_reflect_core::{EnumCase, Inflectable, Inflection, Type};
+use type_reflect_core::{EnumCase, Inflection, Type};
 
 use crate::{
-    ts_validation::validation::type_validation,
+    ts_validation::validation::{field_path, type_validation},
     type_script::untagged_enum_type::emit_case_type_name,
 };
 
 pub fn union_case_validation(case: &EnumCase, parent_name: &str, inflection: Inflection) -> String {
-    let case_key = case.name.inflect(inflection);
+    let case_key = case.serialized_name(inflection);
     let _case_type_name = emit_case_type_name(case, parent_name);
 
     let case_validation = match &case.type_ {
@@ -18,17 +18,27 @@ pub fn union_case_validation(case: &EnumCase, parent_name: &str, inflection: Inf
             validate_tuple_case(case, items, parent_name, &case_key)
         }
         type_reflect_core::TypeFieldsDefinition::Named(_) => {
-            validate_struct_case(case, parent_name, &case_key)
+            validate_case_type(case, parent_name, &case_key)
         }
     };
 
+    let content = content_var(&case_key);
     ts_string! {
-        if (input.#case_key) {
+        if (#content !== undefined) {
             #case_validation
         }
     }
 }
 
+/// The content of the case, which is accessed by a quoted key since the name of the case
+/// isn't always a valid identifier, i.e. with `#[serde(rename_all = "kebab-case")]`
+fn content_var(case_key: &str) -> String {
+    format!(
+        "input[{}]",
+        serde_json::to_string(case_key).expect("a string always serializes")
+    )
+}
+
 fn validate_tuple_case(
     case: &EnumCase,
     tuple_members: &Vec<Type>,
@@ -39,23 +49,22 @@ fn validate_tuple_case(
         let Some(case_type) = tuple_members.first() else {
             return "_ERROR_NO_CASE_TYPE_EXISTS_".to_string();
         };
-        let var_name = ts_string! { input.#case_key };
-        let val = type_validation(&var_name, case_type);
+        let var_name = content_var(case_key);
+        let val = type_validation(&var_name, &field_path("path", case_key), case_type);
         ts_string! {
             #val
             return input as #parent_name;
         }
     } else {
-        let case_type = format!("{}Case{}", parent_name, case.name);
-        ts_string! {
-            return { #case_key: #case_type.validate(input.#case_key) };
-        }
+        validate_case_type(case, parent_name, case_key)
     }
 }
 
-fn validate_struct_case(case: &EnumCase, parent_name: &str, case_key: &str) -> String {
+/// Validates the content of the case with its case type
+fn validate_case_type(case: &EnumCase, parent_name: &str, case_key: &str) -> String {
     let case_type = format!("{}Case{}", parent_name, case.name);
-    ts_string! {
-        return { #case_key: #case_type.validate(input.#case_key) };
-    }
+    let path = field_path("path", case_key);
+    let content = content_var(case_key);
+    let key = serde_json::to_string(case_key).expect("a string always serializes");
+    format!("return {{ {key}: {case_type}.validateAt({content}, {path}, errors) }};")
 }
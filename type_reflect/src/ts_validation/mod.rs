@@ -1,25 +1,57 @@
 use std::ffi::OsStr;
 
-use crate::{AliasType, EnumReflectionType, StructType, TypeEmitter};
+use crate::{with_option_style, AliasType, EnumReflectionType, OptionStyle, StructType, TypeEmitter};
 
 mod struct_type;
 use struct_type::struct_impl;
 
 mod enum_type;
 use enum_type::emit_enum_type;
+
+mod alias_type;
+use alias_type::emit_alias_type;
 use ts_quote::ts_string;
 
 mod validation;
 
 #[derive(Default)]
-pub struct TSValidation {}
+pub struct TSValidation {
+    /// How `Option` fields are represented, unless overridden
+    /// with `#[reflect(option_style = "..")]`
+    pub option_style: OptionStyle,
+    /// Reject unknown fields for all types, rather than only for those
+    /// declared with `#[serde(deny_unknown_fields)]`
+    pub strict: bool,
+}
 
 impl TypeEmitter for TSValidation {
     fn prefix(&mut self) -> String {
         r#"
+            export type ValidationError = { path: string, message: string };
+
+            export type ValidationResult<T> =
+                | { ok: true, value: T }
+                | { ok: false, errors: Array<ValidationError> };
+
+            export class ValidationFailure extends Error {
+                errors: Array<ValidationError>;
+
+                constructor(name: string, errors: Array<ValidationError>) {
+                    const details = errors.map(error => `${error.path || '/'}: ${error.message}`);
+                    super(`Error validating ${name}: ${details.join(', ')}`);
+                    // Keeps `instanceof` working when compiled to ES5
+                    Object.setPrototypeOf(this, ValidationFailure.prototype);
+                    this.errors = errors;
+                }
+            }
+
             function isRecord(value: any): value is Record<string, any> {
                 return typeof value === 'object' && value !== null && !Array.isArray(value);
             }
+
+            function pointer(path: string, key: string | number): string {
+                return `${path}/${String(key).replace(/~/g, '~0').replace(/\//g, '~1')}`;
+            }
         "#
         .to_string()
     }
@@ -28,22 +60,25 @@ impl TypeEmitter for TSValidation {
     where
         T: StructType,
     {
-        let name = T::name();
-        struct_impl(name, &T::fields(), T::inflection())
+        with_option_style(self.option_style, || {
+            let strict = self.strict || T::deny_unknown_fields();
+            struct_impl(T::name(), &T::fields(), T::inflection(), strict)
+        })
     }
 
     fn emit_enum<T>(&mut self) -> String
     where
         T: EnumReflectionType,
     {
-        emit_enum_type::<T>()
+        let strict = self.strict || T::deny_unknown_fields();
+        with_option_style(self.option_style, || emit_enum_type::<T>(strict))
     }
 
     fn emit_alias<T>(&mut self) -> String
     where
         T: AliasType,
     {
-        "".to_string()
+        with_option_style(self.option_style, emit_alias_type::<T>)
     }
 
     fn finalize<P>(&mut self, _path: P) -> Result<(), std::io::Error>
@@ -54,24 +89,59 @@ impl TypeEmitter for TSValidation {
     }
 }
 
+/// Emits the validation namespace of a type, along with its `is{name}` type guard
+///
+/// `validation_impl` is the body of `validateAt`, which records the errors of `input`
+/// in `errors` relative to the JSON pointer `path`, and returns the validated value.
+/// The other functions of the namespace are built on `validateAt`.
 pub fn validation_namespace(name: &str, validation_impl: &str) -> String {
+    validation_namespace_with_members(name, validation_impl, "")
+}
+
+/// Emits the validation namespace of a type with additional `members`
+pub fn validation_namespace_with_members(
+    name: &str,
+    validation_impl: &str,
+    members: &str,
+) -> String {
+    let guard = format!("is{name}");
     ts_string! {
+        export function #guard(value: unknown): value is #name {
+            return #name.safeValidate(value).ok;
+        }
+
         export namespace #name {
-            export function validate(input: any): #name {
+            #members
+
+            export function validateAt(input: any, path: string, errors: Array<ValidationError>): #name {
                 #validation_impl
             }
 
+            export function safeValidate(input: any): ValidationResult<#name> {
+                const errors: Array<ValidationError> = [];
+                const value = validateAt(input, "", errors);
+                if (errors.length > 0) {
+                    return { ok: false, errors };
+                }
+                return { ok: true, value };
+            }
+
+            export function validate(input: any): #name {
+                const result = safeValidate(input);
+                if (!result.ok) {
+                    throw new ValidationFailure(# "\"#name\"", result.errors);
+                }
+                return result.value;
+            }
+
             export function parse(input: string): #name {
                 let json = JSON.parse(input);
                 return validate(json);
             }
 
             export function tryValidate(input: any): #name | undefined {
-                try {
-                    return validate(input);
-                } catch {
-                    return undefined;
-                }
+                const result = safeValidate(input);
+                return result.ok ? result.value : undefined;
             }
 
             export function tryParse(input: string): #name | undefined {
@@ -79,14 +149,25 @@ pub fn validation_namespace(name: &str, validation_impl: &str) -> String {
                 return tryValidate(json);
             }
 
-            export function validateArray(input: any): Array<#name> {
+            export function safeValidateArray(input: any): ValidationResult<Array<#name>> {
+                const errors: Array<ValidationError> = [];
                 if (!Array.isArray(input)) {
-                    throw new Error(# "`Error validating Array<#name>: expected: Array, found: ${ typeof input }`");
+                    errors.push({ path: "", message: # "`expected: Array, found: ${ typeof input }`" });
+                    return { ok: false, errors };
+                }
+                const value = input.map((item: any, i: number) => validateAt(item, pointer("", i), errors));
+                if (errors.length > 0) {
+                    return { ok: false, errors };
                 }
-                for (const item of input) {
-                    validate(item);
+                return { ok: true, value };
+            }
+
+            export function validateArray(input: any): Array<#name> {
+                const result = safeValidateArray(input);
+                if (!result.ok) {
+                    throw new ValidationFailure(# "\"Array<#name>\"", result.errors);
                 }
-                return input as Array<#name>;
+                return result.value;
             }
 
             export function parseArray(input: string): Array<#name> {
@@ -95,19 +176,13 @@ pub fn validation_namespace(name: &str, validation_impl: &str) -> String {
             }
 
             export function tryValidateArray(input: any): Array<#name> | undefined {
-                try {
-                    return validateArray(input);
-                } catch (e: any) {
-                    return undefined;
-                }
+                const result = safeValidateArray(input);
+                return result.ok ? result.value : undefined;
             }
 
-            export function tryParseArray(input: any): Array<#name> | undefined {
-                try {
-                    return parseArray(input);
-                } catch (e: any) {
-                    return undefined;
-                }
+            export function tryParseArray(input: string): Array<#name> | undefined {
+                let json = JSON.parse(input);
+                return tryValidateArray(json);
             }
         }
     }
@@ -1,32 +1,35 @@
-use type_reflect_core::{Inflectable, Inflection, NamedField, TypeFieldsDefinition};
+use type_reflect_core::{Inflection, NamedField, Type, TypeFieldsDefinition};
 
 use super::{
-    validation::{tuple_validation, type_validation},
+    validation::{
+        field_path, null_validation, option_validation, push_error, tuple_validation,
+        type_validation, unknown_fields_validation,
+    },
     validation_namespace,
 };
+use crate::option_style;
 use ts_quote::*;
 
 pub fn named_field_validations(
     member_prefix: &str,
+    path: &str,
     members: &Vec<NamedField>,
     inflection: Inflection,
 ) -> String {
     let members: Vec<String> = members
         .iter()
         .map(|member| {
-            let member_name = member.name.inflect(inflection);
-            type_validation(
-                ts_string! {
-                    #{member_prefix}.#{member_name}
+            let member_name = member.serialized_name(inflection);
+            let var_name = ts_string! {
+                #{member_prefix}.#{member_name}
+            };
+            let path = field_path(path, &member_name);
+            match &member.type_ {
+                Type::Option(inner) => {
+                    option_validation(&var_name, &path, inner, option_style(member.option_style))
                 }
-                .as_str(),
-                &member.type_,
-            )
-
-            // type_validation(
-            //     format!("{}.{}", member_prefix, member_name).as_str(),
-            //     &member.type_,
-            // )
+                type_ => type_validation(&var_name, &path, type_),
+            }
         })
         .collect();
     members.join("\n  ")
@@ -34,49 +37,53 @@ pub fn named_field_validations(
 
 pub fn struct_field_validations(
     member_prefix: &str,
+    path: &str,
     fields: &TypeFieldsDefinition,
     inflection: Inflection,
 ) -> String {
     match fields {
-        TypeFieldsDefinition::Unit => todo!(),
-        TypeFieldsDefinition::Tuple(tuple) => tuple_validation(member_prefix, tuple),
+        TypeFieldsDefinition::Unit => null_validation(member_prefix, path),
+        TypeFieldsDefinition::Tuple(tuple) => tuple_validation(member_prefix, path, tuple),
         TypeFieldsDefinition::Named(named) => {
-            named_field_validations(member_prefix, named, inflection)
+            named_field_validations(member_prefix, path, named, inflection)
         }
     }
 }
 
-pub fn struct_impl(name: &str, fields: &TypeFieldsDefinition, inflection: Inflection) -> String {
-    let validations = struct_field_validations("input", fields, inflection);
+pub fn struct_impl(
+    name: &str,
+    fields: &TypeFieldsDefinition,
+    inflection: Inflection,
+    strict: bool,
+) -> String {
+    let validations = struct_field_validations("input", "path", fields, inflection);
+    let unknown_fields = match fields {
+        TypeFieldsDefinition::Named(named) if strict => {
+            unknown_fields_validation("input", "path", named, inflection, &[])
+        }
+        _ => String::new(),
+    };
 
     let validation_impl = match fields {
-        TypeFieldsDefinition::Unit => todo!(),
-        TypeFieldsDefinition::Tuple(_) => {
+        TypeFieldsDefinition::Unit | TypeFieldsDefinition::Tuple(_) => {
             ts_string! {
                 #validations
                 return input as #name;
             }
         }
-        TypeFieldsDefinition::Named(_) => ts_string! {
-            if (!isRecord(input)) {
-                throw new Error(#r#"`Error parsing #name#: expected: Record, found: ${typeof input}`"#);
+        TypeFieldsDefinition::Named(_) => {
+            let error = push_error("path", "`expected: Record, found: ${typeof input}`");
+            ts_string! {
+                if (!isRecord(input)) {
+                    #error
+                    return input as #name;
+                }
+                #unknown_fields
+                #validations
+                return input as #name;
             }
-            #validations
-            return input as #name;
-        },
+        }
     };
 
-    //     let validation_impl = format!(
-    //         r#"
-    //         if (!isRecord(input)) {{
-    //             throw new Error(`Error parsing {name}: expected: Record, found: ${{typeof input}}`);
-    //         }}
-    //         {validations}
-    //         return input as {name};
-    // "#,
-    //         name = name,
-    //         validations = validations
-    //     );
-
     validation_namespace(name, validation_impl.as_str())
 }
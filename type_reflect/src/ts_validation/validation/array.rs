@@ -1,17 +1,28 @@
-use ts_quote::ts_string;
-
 use crate::ts_validation::validation::type_validation;
 
-pub fn array_validation(var_name: &str, item_type: &type_reflect_core::Type) -> String {
-    let item_validation = type_validation(&format!("{var_name}[i]"), item_type);
+use super::{loop_var, member_path, push_error};
+
+pub fn array_validation(var_name: &str, path: &str, item_type: &type_reflect_core::Type) -> String {
+    let i = loop_var("i", var_name);
+    let item_validation = type_validation(
+        &format!("{var_name}[{i}]"),
+        &member_path(path, &i),
+        item_type,
+    );
+    let error = push_error(
+        path,
+        &format!("`expected: Array, found: ${{ typeof {var_name} }}`"),
+    );
 
-    ts_string! {
-        if (!Array.isArray(#var_name)) {
-            throw new Error(# "`Error parsing #var_name: expected: Array, found: ${ typeof #var_name }`");
-        }
-        for (let i = 0; i < #var_name.length; i++) {
-            #item_validation
-        }
-    }
-    .to_string()
-}
\ No newline at end of file
+    format!(
+        r#"
+    if (!Array.isArray({var_name})) {{
+        {error}
+    }} else {{
+        for (let {i} = 0; {i} < {var_name}.length; {i}++) {{
+            {item_validation}
+        }}
+    }}
+    "#
+    )
+}
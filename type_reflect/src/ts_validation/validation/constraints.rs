@@ -0,0 +1,110 @@
+use type_reflect_core::{Constraints, StringFormat};
+
+use super::push_error;
+
+/// Emits the checks for the constraints on a value, which has already been validated against its type
+pub fn constraints_validation(var_name: &str, path: &str, constraints: &Constraints) -> String {
+    let mut result = String::new();
+
+    // Like the `validator` crate, the length of a string is counted in characters
+    // rather than UTF-16 code units, so a surrogate pair counts once
+    let length = format!(
+        r#"(typeof {var_name} === "string" ? [...{var_name}].length : {var_name}.length)"#
+    );
+
+    if let Some(min_len) = constraints.min_len {
+        let error = push_error(
+            path,
+            &format!("`expected length of at least {min_len}, found: ${{ {length} }}`"),
+        );
+        result.push_str(&format!(
+            r#"
+    if ({length} < {min_len}) {{
+        {error}
+    }}
+    "#
+        ));
+    }
+    if let Some(max_len) = constraints.max_len {
+        let error = push_error(
+            path,
+            &format!("`expected length of at most {max_len}, found: ${{ {length} }}`"),
+        );
+        result.push_str(&format!(
+            r#"
+    if ({length} > {max_len}) {{
+        {error}
+    }}
+    "#
+        ));
+    }
+    if let Some(min) = constraints.min {
+        let error = push_error(
+            path,
+            &format!("`expected a value of at least {min}, found: ${{ {var_name} }}`"),
+        );
+        result.push_str(&format!(
+            r#"
+    if ({var_name} < {min}) {{
+        {error}
+    }}
+    "#
+        ));
+    }
+    if let Some(max) = constraints.max {
+        let error = push_error(
+            path,
+            &format!("`expected a value of at most {max}, found: ${{ {var_name} }}`"),
+        );
+        result.push_str(&format!(
+            r#"
+    if ({var_name} > {max}) {{
+        {error}
+    }}
+    "#
+        ));
+    }
+    if let Some(pattern) = &constraints.pattern {
+        let pattern = serde_json::to_string(pattern).expect("a string always serializes");
+        result.push_str(&pattern_validation(
+            var_name,
+            path,
+            &format!("new RegExp({pattern})"),
+            &format!(r#""the pattern " + {pattern}"#),
+        ));
+    }
+    if constraints.email {
+        result.push_str(&pattern_validation(
+            var_name,
+            path,
+            r"/^[^\s@]+@[^\s@]+\.[^\s@]+$/",
+            r#""an email address""#,
+        ));
+    }
+    if constraints.url {
+        result.push_str(&pattern_validation(
+            var_name,
+            path,
+            &format!("/{}/", StringFormat::Url.pattern()),
+            r#""a URL""#,
+        ));
+    }
+
+    result
+}
+
+/// Emits a check that the value matches the regular expression,
+/// where the description is a TypeScript string expression
+fn pattern_validation(var_name: &str, path: &str, regex: &str, description: &str) -> String {
+    let error = push_error(
+        path,
+        &format!(r#""expected to match " + {description} + ", found: " + JSON.stringify({var_name})"#),
+    );
+    format!(
+        r#"
+    if (!{regex}.test({var_name})) {{
+        {error}
+    }}
+    "#
+    )
+}
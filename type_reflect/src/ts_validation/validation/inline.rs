@@ -0,0 +1,28 @@
+use type_reflect_core::{InlineType, TypeFieldsDefinition};
+
+use crate::ts_validation::struct_type::named_field_validations;
+
+use super::{null_validation, push_error, tuple_validation};
+
+pub fn inline_validation(var_name: &str, path: &str, inline: &InlineType) -> String {
+    match &inline.fields {
+        TypeFieldsDefinition::Unit => null_validation(var_name, path),
+        TypeFieldsDefinition::Tuple(tuple) => tuple_validation(var_name, path, tuple),
+        TypeFieldsDefinition::Named(named) => {
+            let validations = named_field_validations(var_name, path, named, inline.inflection);
+            let error = push_error(
+                path,
+                &format!("`expected: Record, found: ${{ typeof {var_name} }}`"),
+            );
+            format!(
+                r#"
+    if (!isRecord({var_name})) {{
+        {error}
+    }} else {{
+        {validations}
+    }}
+    "#
+            )
+        }
+    }
+}
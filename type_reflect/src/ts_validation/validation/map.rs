@@ -2,16 +2,27 @@ use type_reflect_core::Type;
 
 use crate::ts_validation::validation::type_validation;
 
-pub fn map_validation(var_name: &str, member_type: &Type) -> String {
-    let validation = type_validation("item", member_type);
+use super::{loop_var, member_path, push_error};
+
+pub fn map_validation(var_name: &str, path: &str, member_type: &Type) -> String {
+    let key = loop_var("key", var_name);
+    let validation = type_validation(
+        &format!("{var_name}[{key}]"),
+        &member_path(path, &key),
+        member_type,
+    );
+    let error = push_error(
+        path,
+        &format!("`expected: Record, found: ${{ typeof {var_name} }}`"),
+    );
     format!(
         r#"
     if (!isRecord({var_name})) {{
-        throw new Error(`Error parsing {var_name}: expected: Record, found: ${{ typeof {var_name} }}`);
-    }}
-    for (const key in {var_name}) {{
-        const item = {var_name}[key];
-        {validation}
+        {error}
+    }} else {{
+        for (const {key} in {var_name}) {{
+            {validation}
+        }}
     }}
     "#,
         var_name = var_name,
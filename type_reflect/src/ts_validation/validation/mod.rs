@@ -9,5 +9,42 @@ pub use array::*;
 
 mod map;
 
+mod string_format;
+
+mod inline;
+
+mod constraints;
+
 mod tuple;
 pub use tuple::*;
+
+mod unknown_fields;
+pub use unknown_fields::*;
+
+/// Emits a statement which records a validation error at `path`
+///
+/// Both arguments are TypeScript expressions, i.e. `pointer(path, "name")`
+/// and a template literal for the message.
+pub fn push_error(path: &str, message: &str) -> String {
+    format!("errors.push({{ path: {path}, message: {message} }});")
+}
+
+/// Returns the expression of the JSON pointer to a member of the value at `path`,
+/// where `key` is a TypeScript expression
+pub fn member_path(path: &str, key: &str) -> String {
+    format!("pointer({path}, {key})")
+}
+
+/// Returns the expression of the JSON pointer to a named field of the value at `path`
+pub fn field_path(path: &str, name: &str) -> String {
+    member_path(
+        path,
+        &serde_json::to_string(name).expect("a string always serializes"),
+    )
+}
+
+/// Returns a loop variable for iterating over `var_name`, which is unique
+/// among the loops it's nested in, since each nested element adds an index to the name
+pub fn loop_var(prefix: &str, var_name: &str) -> String {
+    format!("{}{}", prefix, var_name.matches('[').count())
+}
@@ -1,14 +1,33 @@
-pub fn primitive_type_validation(var_name: &str, primitive_type: &str) -> String {
+use super::push_error;
+
+pub fn primitive_type_validation(var_name: &str, path: &str, primitive_type: &str) -> String {
+    let error = push_error(
+        path,
+        &format!("`expected: {primitive_type}, found: ${{ typeof {var_name} }}`"),
+    );
     format!(
         r#"
     if ('{primitive_type}' !== typeof {var_name}) {{
-        throw new Error(`Validation error: expected: {primitive_type}, found: ${{ typeof {var_name} }}`);
+        {error}
     }}
     "#,
         var_name = var_name,
-        primitive_type = primitive_type
+        primitive_type = primitive_type,
+        error = error
     )
 }
 
-// Better error?
-// throw new Error(`Error parsing {parent_name}.{name}: expected: {primitive}, found: ${{ typeof input.{name} }}`);
+/// Validates a unit value, which serde serializes as `null`
+pub fn null_validation(var_name: &str, path: &str) -> String {
+    let error = push_error(
+        path,
+        &format!("`expected: null, found: ${{ typeof {var_name} }}`"),
+    );
+    format!(
+        r#"
+    if ({var_name} !== null) {{
+        {error}
+    }}
+    "#
+    )
+}
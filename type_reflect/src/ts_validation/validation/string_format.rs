@@ -0,0 +1,28 @@
+use type_reflect_core::StringFormat;
+
+use super::push_error;
+
+pub fn string_format_validation(var_name: &str, path: &str, format: &StringFormat) -> String {
+    let type_error = push_error(
+        path,
+        &format!("`expected: string, found: ${{ typeof {var_name} }}`"),
+    );
+    let format_error = push_error(
+        path,
+        &format!(
+            "`expected: {description}, found: ${{ JSON.stringify({var_name}) }}`",
+            description = format.description()
+        ),
+    );
+    format!(
+        r#"
+    if ('string' !== typeof {var_name}) {{
+        {type_error}
+    }} else if (!/{pattern}/.test({var_name})) {{
+        {format_error}
+    }}
+    "#,
+        var_name = var_name,
+        pattern = format.pattern(),
+    )
+}
@@ -1,24 +1,38 @@
-use ts_quote::ts_string;
-
 use crate::ts_validation::validation::type_validation;
 
-pub fn tuple_validation(var_name: &str, members: &Vec<type_reflect_core::Type>) -> String {
+use super::{member_path, push_error};
+
+pub fn tuple_validation(
+    var_name: &str,
+    path: &str,
+    members: &Vec<type_reflect_core::Type>,
+) -> String {
+    // A newtype is serialized as its content, rather than as an array
+    if let [member] = members.as_slice() {
+        return type_validation(var_name, path, member);
+    }
     let member_validations: Vec<String> = members
         .iter()
         .enumerate()
         .map(|(i, member_type)| {
             let member_var = format!("{}[{}]", var_name, i);
-            type_validation(&member_var, member_type)
+            type_validation(&member_var, &member_path(path, &i.to_string()), member_type)
         })
         .collect();
 
     let member_validations = member_validations.join("\n");
+    let error = push_error(
+        path,
+        &format!("`expected: Array, found: ${{ typeof {var_name} }}`"),
+    );
 
-    ts_string! {
-        if (!Array.isArray(#var_name)) {
-            throw new Error(# "`Error parsing #var_name: expected: Array, found: ${ typeof #var_name }`");
-        }
-        #member_validations
-    }
-    .to_string()
-}
\ No newline at end of file
+    format!(
+        r#"
+    if (!Array.isArray({var_name})) {{
+        {error}
+    }} else {{
+        {member_validations}
+    }}
+    "#
+    )
+}
@@ -1,40 +1,107 @@
-use type_reflect_core::Type;
+use type_reflect_core::{OptionStyle, Type, TypeOverride};
 
-use crate::type_script::to_ts_type;
+use crate::{type_override, type_script::to_ts_type};
 
-use super::{array_validation, map::map_validation, primitive_type_validation};
+use super::{
+    array_validation, constraints::constraints_validation, inline::inline_validation,
+    map::map_validation, null_validation, primitive_type_validation, push_error,
+    string_format::string_format_validation,
+};
 
-pub fn type_validation(var_name: &str, type_: &Type) -> String {
+/// Emits the validation of the value `var_name` against `type_`, which records
+/// any errors in `errors` with the JSON pointer `path` rather than throwing
+pub fn type_validation(var_name: &str, path: &str, type_: &Type) -> String {
     match type_ {
-        Type::String => primitive_type_validation(var_name, "string"),
+        Type::String => primitive_type_validation(var_name, path, "string"),
+        Type::FormattedString(format) => string_format_validation(var_name, path, format),
         Type::Float | Type::Int | Type::UnsignedInt => {
-            primitive_type_validation(var_name, "number")
+            primitive_type_validation(var_name, path, "number")
         }
-        Type::Boolean => primitive_type_validation(var_name, "boolean"),
-        Type::Array(t) => array_validation(var_name, t),
-        Type::Map { key: _, value } => map_validation(var_name, value),
-        Type::Option(t) => {
-            let type_validation = type_validation(var_name, t);
-            format!(
-                r#"
-                if ({var_name}) {{
+        Type::Boolean => primitive_type_validation(var_name, path, "boolean"),
+        Type::Null => null_validation(var_name, path),
+        Type::Array(t) => array_validation(var_name, path, t),
+        Type::Map { key: _, value } => map_validation(var_name, path, value),
+        // Only a field can be omitted, so any other `None` is `null`
+        Type::Option(t) => option_validation(var_name, path, t, OptionStyle::Nullable),
+        Type::Named(t) => match type_override(&t.name)
+            .and_then(|overrides| override_validation(var_name, path, &overrides))
+        {
+            Some(validation) => validation,
+            None => {
+                let value_type = to_ts_type(type_);
+                format!(
+                    r#"
+                {value_type}.validateAt({var_name}, {path}, errors);
+                "#,
+                    var_name = var_name,
+                    value_type = value_type
+                )
+            }
+        },
+        Type::Transparent(type_) => type_validation(var_name, path, &(type_.type_)),
+        Type::Override { type_, overrides } => override_validation(var_name, path, overrides)
+            .unwrap_or_else(|| type_validation(var_name, path, type_)),
+        // The constraints are only checked if the value has the expected type
+        Type::Constrained { type_, constraints } => format!(
+            r#"
+                {{
+                    const errorCount = errors.length;
                     {type_validation}
+                    if (errors.length === errorCount) {{
+                        {constraints_validation}
+                    }}
                 }}
                 "#,
-                var_name = var_name,
-                type_validation = type_validation
-            )
+            type_validation = type_validation(var_name, path, type_),
+            constraints_validation = constraints_validation(var_name, path, constraints)
+        ),
+        Type::Inline(inline) => inline_validation(var_name, path, inline),
+    }
+}
+
+/// Returns the validation for an `Option`, which only validates the inner type
+/// if the value is present in the given style
+///
+/// An optional value may also be `null`, since that's how serde serializes `None`
+/// unless the field is skipped.
+pub fn option_validation(var_name: &str, path: &str, inner: &Type, style: OptionStyle) -> String {
+    let condition = match style {
+        OptionStyle::Nullable => format!("{var_name} !== null"),
+        OptionStyle::Optional | OptionStyle::Nullish => {
+            format!("{var_name} !== undefined && {var_name} !== null")
         }
-        Type::Named(_) => {
-            let value_type = to_ts_type(type_);
-            format!(
-                r#"
-                {value_type}.validate({var_name})
+    };
+    let type_validation = type_validation(var_name, path, inner);
+    format!(
+        r#"
+                if ({condition}) {{
+                    {type_validation}
+                }}
                 "#,
-                var_name = var_name,
-                value_type = value_type
-            )
-        }
-        Type::Transparent(type_) => type_validation(var_name, &(type_.type_)),
+        condition = condition,
+        type_validation = type_validation
+    )
+}
+
+/// Returns the validation for an overridden type, or `None` if the reflected type should be validated
+///
+/// The validation function throws if the value is invalid, so its error is recorded at `path`.
+fn override_validation(var_name: &str, path: &str, overrides: &TypeOverride) -> Option<String> {
+    match (&overrides.validation, &overrides.type_script) {
+        (Some(validation), _) => Some(format!(
+            r#"
+                try {{
+                    ({validation})({var_name});
+                }} catch (e: any) {{
+                    {error}
+                }}
+                "#,
+            validation = validation,
+            var_name = var_name,
+            error = push_error(path, "e instanceof Error ? e.message : String(e)")
+        )),
+        // A user-defined TypeScript type can't be checked without a validation override
+        (None, Some(_)) => Some(String::new()),
+        (None, None) => None,
     }
 }
@@ -0,0 +1,44 @@
+use type_reflect_core::{Inflection, NamedField};
+
+use super::{member_path, push_error};
+
+/// Returns the names a field is accepted under, including its directional renames and aliases
+fn accepted_names(field: &NamedField, inflection: Inflection) -> Vec<String> {
+    let directions = &field.directions;
+    let mut names = vec![field.serialized_name(inflection)];
+    names.extend(directions.serialize_name.iter().cloned());
+    names.extend(directions.deserialize_name.iter().cloned());
+    names.extend(directions.aliases.iter().cloned());
+    names
+}
+
+/// Returns a validation which rejects the keys of the record `var_name` other than
+/// those of `fields` and `other_keys`, like `#[serde(deny_unknown_fields)]`
+pub fn unknown_fields_validation(
+    var_name: &str,
+    path: &str,
+    fields: &[NamedField],
+    inflection: Inflection,
+    other_keys: &[&str],
+) -> String {
+    let mut keys: Vec<String> = fields
+        .iter()
+        .flat_map(|field| accepted_names(field, inflection))
+        .chain(other_keys.iter().map(|key| key.to_string()))
+        .collect();
+    keys.sort();
+    keys.dedup();
+    let keys = serde_json::to_string(&keys).expect("strings always serialize");
+    let error = push_error(&member_path(path, "key"), "`unknown field: ${key}`");
+    format!(
+        r#"
+    for (const key of Object.keys({var_name})) {{
+        if (!{keys}.includes(key)) {{
+            {error}
+        }}
+    }}
+    "#,
+        var_name = var_name,
+        keys = keys
+    )
+}
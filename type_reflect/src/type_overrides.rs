@@ -0,0 +1,114 @@
+use std::cell::RefCell;
+use std::collections::HashMap;
+
+use type_reflect_core::TypeOverride;
+
+use crate::{Definition, Emittable};
+
+thread_local! {
+    static TYPE_OVERRIDES: RefCell<TypeOverrides> = RefCell::new(TypeOverrides::default());
+}
+
+/// `TypeOverrides` maps Rust type names to user-defined target expressions.
+///
+/// Generally these are declared in the `type_overrides` section
+/// of the `export_types!` macro:
+///
+/// ```ignore
+/// export_types!(
+///     types: [ Foo ],
+///     type_overrides: [
+///         NaiveDate: (type: "Date", zod: "z.coerce.date()"),
+///     ],
+///     destinations: [ ... ]
+/// )
+/// ```
+///
+/// While installed, any `Type::Named` with a matching name is emitted
+/// using the override by `TypeScript`, `Zod` and `TSValidation`.
+///
+/// The overrides are installed per thread, and `export_types!` only keeps them installed
+/// for the duration of the export, so they don't leak into the next export or into
+/// exports running on other threads.
+///
+/// The export names of the exported types are installed alongside the overrides,
+/// so references to a type declared with `#[reflect(export_name = "..")]` use the
+/// same name as its declaration, and their definitions, so values which reference
+/// them can be rendered.
+#[derive(Default, Clone, Debug)]
+pub struct TypeOverrides {
+    overrides: HashMap<String, TypeOverride>,
+    export_names: HashMap<String, String>,
+    definitions: HashMap<String, Definition>,
+}
+
+impl TypeOverrides {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn with(mut self, type_name: &str, type_override: TypeOverride) -> Self {
+        self.overrides.insert(type_name.to_string(), type_override);
+        self
+    }
+
+    /// Registers the export name of `T`, if it is exported under a name other than `type_name`
+    pub fn with_export_name<T: Emittable>(mut self, type_name: &str) -> Self {
+        if let Some(export_name) = T::export_name() {
+            self.export_names
+                .insert(type_name.to_string(), export_name.to_string());
+        }
+        self
+    }
+
+    /// Registers the definition of `T`, if it's reflected
+    pub fn with_definition<T: Emittable>(mut self, type_name: &str) -> Self {
+        if let Some(definition) = T::definition() {
+            self.definitions.insert(type_name.to_string(), definition);
+        }
+        self
+    }
+
+    /// Installs the overrides for the current thread, in place of any installed overrides.
+    ///
+    /// The previously installed overrides are restored when the returned guard is dropped,
+    /// including when the export returns early with an error.
+    pub fn install(self) -> TypeOverridesGuard {
+        let previous = TYPE_OVERRIDES.with(|overrides| overrides.replace(self));
+        TypeOverridesGuard { previous }
+    }
+}
+
+pub struct TypeOverridesGuard {
+    previous: TypeOverrides,
+}
+
+impl Drop for TypeOverridesGuard {
+    fn drop(&mut self) {
+        let previous = std::mem::take(&mut self.previous);
+        TYPE_OVERRIDES.with(|overrides| overrides.replace(previous));
+    }
+}
+
+/// Returns the installed override for the given type name, if any
+pub fn type_override(type_name: &str) -> Option<TypeOverride> {
+    TYPE_OVERRIDES.with(|overrides| overrides.borrow().overrides.get(type_name).cloned())
+}
+
+/// Returns the name a type is exported under, which is the type name unless
+/// an export name was installed for it
+pub fn export_name(type_name: &str) -> String {
+    TYPE_OVERRIDES.with(|overrides| {
+        overrides
+            .borrow()
+            .export_names
+            .get(type_name)
+            .cloned()
+            .unwrap_or_else(|| type_name.to_string())
+    })
+}
+
+/// Returns the installed definition of the given type name, if any
+pub fn definition(type_name: &str) -> Option<Definition> {
+    TYPE_OVERRIDES.with(|overrides| overrides.borrow().definitions.get(type_name).cloned())
+}
@@ -1,18 +1,19 @@
 use crate::AliasType;
 
-use super::to_ts_type;
+use super::{jsdoc, to_ts_type};
 
 pub fn emit_alias_type<T>() -> String
 where
     T: AliasType,
 {
+    let source = to_ts_type(&T::source_type());
     format!(
         r#"
 
-export type {alias} = {source};
+{docs}export type {alias} = {source};
 
 "#,
+        docs = jsdoc(&T::docs()),
         alias = T::name(),
-        source = to_ts_type(&T::source_type())
     )
 }
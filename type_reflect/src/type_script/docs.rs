@@ -0,0 +1,24 @@
+use type_reflect_core::Documentation;
+
+/// Renders the documentation as a JSDoc comment, followed by a newline,
+/// or an empty string if there is no documentation to render.
+pub fn jsdoc(docs: &Documentation) -> String {
+    if docs.is_empty() {
+        return String::new();
+    }
+
+    let mut lines: Vec<String> = vec![];
+    if let Some(description) = &docs.description {
+        lines.extend(description.lines().map(|line| line.to_string()));
+    }
+    if let Some(deprecated) = &docs.deprecated {
+        lines.push(format!("@deprecated {deprecated}").trim_end().to_string());
+    }
+
+    let lines: Vec<String> = lines
+        .iter()
+        .map(|line| format!(" * {}", line.replace("*/", "*\\/")).trim_end().to_string())
+        .collect();
+
+    format!("/**\n{}\n */\n", lines.join("\n"))
+}
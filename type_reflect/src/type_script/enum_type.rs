@@ -1,11 +1,11 @@
 use ts_quote::ts_string;
-use type_reflect_core::{EnumCase, EnumType, Inflectable, Inflection};
+use type_reflect_core::{EnumCase, EnumType, Inflection, TypeFieldsDefinition};
 
 use super::untagged_enum_type::emit_untagged_enum_type;
 use crate::type_script::type_fields;
 use crate::EnumReflectionType;
 
-use super::to_ts_type;
+use super::{jsdoc, to_ts_type};
 
 pub fn emit_enum_type<T>() -> String
 where
@@ -13,6 +13,7 @@ where
 {
     match T::enum_type() {
         EnumType::Simple => emit_simple_enum_type::<T>(),
+        EnumType::Numeric => emit_numeric_enum_type::<T>(),
         EnumType::Complex {
             case_key,
             content_key,
@@ -29,10 +30,11 @@ where
     let simple_cases: String = T::cases()
         .into_iter()
         .map(|case| {
-            let inflected = case.name.inflect(inflection);
+            let inflected = case.serialized_name(inflection);
             format!(
-                r#"  {name} = "{inflected}",
+                r#"  {docs}{name} = "{inflected}",
 "#,
+                docs = jsdoc(&case.docs),
                 name = case.name
             )
         })
@@ -40,22 +42,58 @@ where
 
     format!(
         r#"
-export enum {name} {{
+{docs}export enum {name} {{
 {simple_cases}}}
 "#,
+        docs = jsdoc(&T::docs()),
         name = T::name(),
         simple_cases = simple_cases,
     )
 }
 
+fn emit_numeric_enum_type<T>() -> String
+where
+    T: EnumReflectionType,
+{
+    let numeric_cases: String = T::cases()
+        .into_iter()
+        .map(|case| {
+            format!(
+                r#"  {docs}{name} = {discriminant},
+"#,
+                docs = jsdoc(&case.docs),
+                name = case.name,
+                discriminant = case.discriminant.unwrap_or_default()
+            )
+        })
+        .collect();
+
+    format!(
+        r#"
+{docs}export enum {name} {{
+{numeric_cases}}}
+"#,
+        docs = jsdoc(&T::docs()),
+        name = T::name(),
+    )
+}
+
 fn emit_complex_enum_type<T>(case_key: &String, content_key: &Option<String>) -> String
 where
     T: EnumReflectionType,
 {
+    // The tag and content keys aren't always valid identifiers, i.e. with
+    // `#[serde(tag = "@type")]`, so they're emitted as string literals
+    let case_key = &serde_json::to_string(case_key).expect("a string always serializes");
+    let content_key = &content_key
+        .as_ref()
+        .map(|key| serde_json::to_string(key).expect("a string always serializes"));
     let cases_union = T::generate_cases_union();
     let case_keys_const = T::generate_case_key_const();
     let union_types = T::generate_union_types(case_key, content_key, T::inflection());
     let union_type = T::generate_union_schema();
+    let constructors = T::generate_constructors(case_key, content_key);
+    let match_fn = T::generate_match_fn(case_key);
 
     // Generate case type
 
@@ -66,6 +104,8 @@ where
 {case_keys_const}
 {union_types}
 {union_type}
+{constructors}
+{match_fn}
 "#
     )
 }
@@ -84,7 +124,7 @@ trait EnumTypeBridge: EnumReflectionType {
         let inflection = Self::inflection();
 
         for case in Self::cases() {
-            let inflected = case.name.inflect(inflection);
+            let inflected = case.serialized_name(inflection);
             case_values.push(format!(r#""{inflected}""#));
         }
 
@@ -104,7 +144,7 @@ trait EnumTypeBridge: EnumReflectionType {
 
         case_values.push_str("\n  ");
         for case in Self::cases() {
-            let inflected = case.name.inflect(inflection);
+            let inflected = case.serialized_name(inflection);
             case_values.push_str(&format!(r#"{name}: "{inflected}""#, name = case.name,));
             case_values.push_str(",\n  ");
         }
@@ -143,13 +183,14 @@ trait EnumTypeBridge: EnumReflectionType {
     ) -> String {
         let case_type_name = union_case_type_name(case, Self::name());
         // let id = Self::case_id(case);
-        let id = &case.name.inflect(inflection);
+        let id = &case.serialized_name(inflection);
+        let docs = jsdoc(&case.docs);
 
         let additional_fields = match &case.type_ {
             type_reflect_core::TypeFieldsDefinition::Unit => {
                 return format!(
                     r#"
-export type {case_type_name} = {{
+{docs}export type {case_type_name} = {{
     {case_key}: "{id}",
 }};
                     "#
@@ -158,9 +199,20 @@ export type {case_type_name} = {{
             type_reflect_core::TypeFieldsDefinition::Tuple(inner) => {
                 let content_key = match content_key {
                     Some(content_key) => content_key,
+                    // An internally tagged newtype variant merges the fields of its content with the tag
+                    None if inner.len() == 1 => {
+                        return format!(
+                            r#"
+{docs}export type {case_type_name} = {{
+    {case_key}: "{id}",
+}} & {type_};
+                    "#,
+                            type_ = to_ts_type(&inner[0])
+                        )
+                    }
                     None => {
                         //TODO: make this a localized Syn error
-                        panic!("Content key required on enums containing at least one tuple-type variant.")
+                        panic!("Content key required on enums containing at least one tuple-type variant with more than one field.")
                     }
                 };
                 if inner.len() == 1 {
@@ -201,7 +253,7 @@ export type {case_type_name} = {{
         };
         format!(
             r#"
-export type {case_type_name} = {{
+{docs}export type {case_type_name} = {{
   {case_key}: "{id}",
   {additional_fields}
 }};
@@ -219,12 +271,121 @@ export type {case_type_name} = {{
 
         format!(
             r#"
-export type {name} = {cases};
+{docs}export type {name} = {cases};
             "#,
+            docs = jsdoc(&Self::docs()),
             cases = cases,
             name = Self::name()
         )
     }
+    /// Emits a function for each case which builds a correctly tagged value of the case
+    fn generate_constructors(case_key: &String, content_key: &Option<String>) -> String {
+        let name = Self::name();
+        let inflection = Self::inflection();
+        let constructors: String = Self::cases()
+            .iter()
+            .map(|case| {
+                let case_type_name = union_case_type_name(case, name);
+                let constructor = case_constructor_name(case);
+                let id = case.serialized_name(inflection);
+                let (params, value) = match (&case.type_, content_key) {
+                    (TypeFieldsDefinition::Unit, _) => {
+                        (String::new(), format!(r#"{{ {case_key}: "{id}" }}"#))
+                    }
+                    (_, Some(content_key)) => (
+                        format!(r#"content: {case_type_name}[{content_key}]"#),
+                        format!(r#"{{ {case_key}: "{id}", {content_key}: content }}"#),
+                    ),
+                    (TypeFieldsDefinition::Tuple(inner), None) => (
+                        format!("content: {}", to_ts_type(&inner[0])),
+                        format!(r#"{{ {case_key}: "{id}", ...content }}"#),
+                    ),
+                    (TypeFieldsDefinition::Named(_), None) => (
+                        format!(r#"content: Omit<{case_type_name}, {case_key}>"#),
+                        format!(r#"{{ {case_key}: "{id}", ...content }}"#),
+                    ),
+                };
+                format!(
+                    r#"
+    export function {constructor}({params}): {case_type_name} {{
+        return {value};
+    }}
+"#
+                )
+            })
+            .collect();
+
+        format!(
+            r#"
+export namespace {name} {{
+{constructors}}}
+"#
+        )
+    }
+
+    /// Emits `match{name}`, which calls the handler of the case of a value
+    ///
+    /// The handlers are required for every case, so TypeScript checks the match is exhaustive
+    fn generate_match_fn(case_key: &String) -> String {
+        let name = Self::name();
+        let inflection = Self::inflection();
+        let mut handlers = String::new();
+        let mut branches = String::new();
+        for case in Self::cases() {
+            let case_type_name = union_case_type_name(&case, name);
+            let key = case_handler_key(&case);
+            let id = case.serialized_name(inflection);
+            handlers.push_str(&format!("        {key}: (value: {case_type_name}) => R,
+"));
+            branches.push_str(&format!(
+                r#"
+        case "{id}":
+            return handlers.{key}(value);"#
+            ));
+        }
+
+        format!(
+            r#"
+export function match{name}<R>(
+    value: {name},
+    handlers: {{
+{handlers}    }},
+): R {{
+    switch (value[{case_key}]) {{
+        {branches}
+        default:
+            throw new Error(`unknown variant: ${{JSON.stringify(value)}}`);
+    }}
+}}
+"#
+        )
+    }
+}
+
+/// Names which can't be declared as a function, or which are already declared
+/// in the namespace of a type by `TSValidation`
+const RESERVED_NAMES: &[&str] = &[
+    "break", "case", "catch", "class", "const", "continue", "debugger", "default", "delete",
+    "do", "else", "enum", "export", "extends", "false", "finally", "for", "function", "if",
+    "import", "in", "instanceof", "new", "null", "return", "super", "switch", "this", "throw",
+    "true", "try", "typeof", "var", "void", "while", "with", "validateAt", "safeValidate",
+    "validate", "parse", "tryValidate", "tryParse", "safeValidateArray", "validateArray",
+    "parseArray", "tryValidateArray", "tryParseArray",
+];
+
+/// The key of the handler of a case passed to `match{name}`, e.g. `inProgress` for `InProgress`
+pub fn case_handler_key(case: &EnumCase) -> String {
+    Inflection::Camel.apply(&case.name)
+}
+
+/// The name of the function which constructs a case, which is the handler key
+/// suffixed with `_` if the name is reserved, e.g. `null_` for `Null`
+pub fn case_constructor_name(case: &EnumCase) -> String {
+    let key = case_handler_key(case);
+    match RESERVED_NAMES.contains(&key.as_str()) {
+        true => format!("{key}_"),
+        false => key,
+    }
 }
 
 pub fn union_case_type_name(case: &EnumCase, parent_name: &str) -> String {
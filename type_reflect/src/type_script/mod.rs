@@ -18,16 +18,29 @@ pub mod untagged_enum_type;
 pub mod type_fields;
 pub use type_fields::*;
 
+pub mod docs;
+pub use docs::*;
+
 mod alias_type;
 pub use alias_type::*;
 
 pub struct TypeScript {
     pub tab_size: u32,
+    /// Also emit `FooInput` and `FooOutput` types for structs which
+    /// are serialized differently than they're deserialized
+    pub io_types: bool,
+    /// How `Option` fields are represented, unless overridden
+    /// with `#[reflect(option_style = "..")]`
+    pub option_style: OptionStyle,
 }
 
 impl Default for TypeScript {
     fn default() -> Self {
-        Self { tab_size: 2 }
+        Self {
+            tab_size: 2,
+            io_types: false,
+            option_style: OptionStyle::Optional,
+        }
     }
 }
 
@@ -38,13 +51,18 @@ pub trait TypeExporter {
 pub fn to_ts_type(t: &Type) -> String {
     match t {
         // TODO: Support generics
-        Type::Named(t) => t.name.to_string(),
+        Type::Named(t) => type_override(&t.name)
+            .and_then(|overrides| overrides.type_script)
+            .unwrap_or_else(|| export_name(&t.name)),
         Type::String => "string".to_string(),
+        Type::FormattedString(_) => "string".to_string(),
         Type::Int => "number".to_string(),
         Type::UnsignedInt => "number".to_string(),
         Type::Float => "number".to_string(),
         Type::Boolean => "boolean".to_string(),
-        Type::Option(t) => to_ts_type(t).to_string(),
+        Type::Null => "null".to_string(),
+        // Only a field can be omitted, so any other `None` is `null`
+        Type::Option(t) => format!("{} | null", to_ts_type(t)),
         Type::Array(t) => format!("Array<{}>", to_ts_type(t)),
         Type::Map { key, value } => {
             format!(
@@ -54,6 +72,27 @@ pub fn to_ts_type(t: &Type) -> String {
             )
         }
         Type::Transparent(t) => to_ts_type(&(t.type_)),
+        Type::Override { type_, overrides } => match &overrides.type_script {
+            Some(type_script) => type_script.clone(),
+            None => to_ts_type(type_),
+        },
+        Type::Constrained { type_, .. } => to_ts_type(type_),
+        Type::Inline(inline) => match &inline.fields {
+            TypeFieldsDefinition::Unit => "null".to_string(),
+            TypeFieldsDefinition::Tuple(tuple) => tuple_fields(tuple),
+            TypeFieldsDefinition::Named(named) => {
+                format!("{{ {} }}", named_fields(named, inline.inflection))
+            }
+        },
+    }
+}
+
+/// Renders a typed constant holding the `#[reflect(default)]` value of a type,
+/// or an empty string if there is no default value.
+pub fn default_const(name: &str, value: Option<String>) -> String {
+    match value {
+        Some(value) => format!("\nexport const default{name}: {name} = {value};\n"),
+        None => String::new(),
     }
 }
 
@@ -66,22 +105,68 @@ impl TypeEmitter for TypeScript {
     where
         T: StructType,
     {
-        let name = T::name();
-        struct_impl(name, &T::fields(), T::inflection())
+        with_option_style(self.option_style, || {
+            let name = T::name();
+            let fields = T::fields();
+            let default =
+                T::default_value().map(|value| Definition::of_struct::<T>().literal(value));
+            let mut result = format!(
+                "{}{}",
+                struct_impl(name, &fields, T::inflection(), &T::docs()),
+                default_const(name, default)
+            );
+            if self.io_types && fields.is_directional() {
+                for (suffix, direction) in [
+                    ("Input", Direction::Deserialize),
+                    ("Output", Direction::Serialize),
+                ] {
+                    result.push_str(&struct_impl(
+                        &format!("{name}{suffix}"),
+                        &fields.in_direction(direction),
+                        T::inflection(),
+                        &T::docs(),
+                    ));
+                }
+            }
+            result
+        })
     }
 
     fn emit_enum<T>(&mut self) -> String
     where
         T: EnumReflectionType,
     {
-        emit_enum_type::<T>()
+        with_option_style(self.option_style, || {
+            format!(
+                "{}{}",
+                emit_enum_type::<T>(),
+                default_const(T::name(), enum_default_literal::<T>())
+            )
+        })
     }
 
     fn emit_alias<T>(&mut self) -> String
     where
         T: AliasType,
     {
-        emit_alias_type::<T>()
+        with_option_style(self.option_style, || {
+            let default = T::default_value().map(|value| json_literal(&T::source_type(), value));
+            format!(
+                "{}{}",
+                emit_alias_type::<T>(),
+                default_const(T::name(), default)
+            )
+        })
+    }
+
+    fn emit_value(&mut self, name: &str, type_: &Type, value: &serde_json::Value) -> String {
+        with_option_style(self.option_style, || {
+            format!(
+                "\nexport const {name}: {type_} = {value};\n",
+                type_ = to_ts_type(type_),
+                value = json_literal(type_, value.clone())
+            )
+        })
     }
 
     fn finalize<P>(&mut self, path: P) -> Result<(), std::io::Error>
@@ -1,10 +1,16 @@
-use super::{named_fields, tuple_fields};
+use super::{jsdoc, named_fields, tuple_fields};
 use ts_quote::ts_string;
-use type_reflect_core::{Inflection, TypeFieldsDefinition};
+use type_reflect_core::{Documentation, Inflection, TypeFieldsDefinition};
 
-pub fn struct_impl(name: &str, fields: &TypeFieldsDefinition, inflection: Inflection) -> String {
+pub fn struct_impl(
+    name: &str,
+    fields: &TypeFieldsDefinition,
+    inflection: Inflection,
+    docs: &Documentation,
+) -> String {
     let fields = match fields {
-        TypeFieldsDefinition::Unit => todo!(),
+        // A unit struct is serialized as `null`
+        TypeFieldsDefinition::Unit => "null".to_string(),
         TypeFieldsDefinition::Tuple(tuple) => {
             let fields = tuple_fields(tuple);
             ts_string! {
@@ -18,7 +24,9 @@ pub fn struct_impl(name: &str, fields: &TypeFieldsDefinition, inflection: Inflec
             }
         }
     };
+    let docs = jsdoc(docs);
     ts_string! {
+        #docs
         export type #name = #fields;
     }
 }
@@ -1,19 +1,26 @@
 use ts_quote::ts_string;
-use type_reflect_core::{Inflectable, Inflection, NamedField, Type};
+use type_reflect_core::{Inflection, NamedField, Type};
 
-use crate::type_script::to_ts_type;
+use crate::option_style;
+use crate::type_script::{jsdoc, to_ts_type};
 
 pub fn named_member(member: &NamedField, inflection: Inflection) -> String {
-    let name = &member.name.inflect(inflection);
+    let name = &member.serialized_name(inflection);
+    let docs = jsdoc(&member.docs);
 
     match &member.type_ {
         type_reflect_core::Type::Option(t) => {
-            let value = to_ts_type(t);
-            format!("{name}?: {value};", name = name, value = value)
+            let style = option_style(member.option_style);
+            let optional = if style.is_optional() { "?" } else { "" };
+            let value = match style.is_nullable() {
+                true => format!("{} | null", to_ts_type(t)),
+                false => to_ts_type(t),
+            };
+            format!("{docs}{name}{optional}: {value};", name = name, value = value)
         }
         t => {
             let value = to_ts_type(t);
-            format!("{name}: {value};", name = name, value = value)
+            format!("{docs}{name}: {value};", name = name, value = value)
         }
     }
 }
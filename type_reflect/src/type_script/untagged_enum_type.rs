@@ -1,10 +1,12 @@
 use crate::EnumReflectionType;
 use ts_quote::ts_string;
 use type_reflect_core::{
-    EnumCase, Inflectable, Inflection, NamedField, Type, TypeFieldsDefinition,
+    EnumCase, Inflection, NamedField, Type, TypeFieldsDefinition,
 };
 
-use super::{to_ts_type, type_fields, union_case_type_name};
+use super::{
+    case_constructor_name, case_handler_key, jsdoc, to_ts_type, type_fields, union_case_type_name,
+};
 
 pub fn emit_untagged_enum_type<T>() -> String
 where
@@ -13,6 +15,7 @@ where
     let name = T::name();
     let cases = T::cases();
     let inflection = T::inflection();
+    let docs = jsdoc(&T::docs());
 
     let unit_cases: Vec<&EnumCase> = cases
         .iter()
@@ -69,35 +72,134 @@ where
         .collect();
     let member_case_types = member_case_types.join("\n");
 
-    match (unit_cases, member_cases_block) {
+    let helpers = format!(
+        "{}{}",
+        emit_constructors(name, &cases, inflection),
+        emit_match_fn(name, &cases, inflection)
+    );
+
+    let type_ = match (unit_cases, member_cases_block) {
         (None, None) => ts_string! {
+            #docs
             export type #name = never;
         },
         (None, Some(members)) => ts_string! {
             #member_case_types
 
+            #docs
             export type #name = #members;
         },
         (Some(units), None) => ts_string! {
+            #docs
             export type #name = #units;
         },
         (Some(units), Some(members)) => ts_string! {
             #member_case_types
 
+            #docs
             export type #name = #units | #members;
         },
+    };
+
+    format!("{type_}{helpers}")
+}
+
+/// Emits a function for each case which builds a value of the case
+fn emit_constructors(name: &str, cases: &[EnumCase], inflection: Inflection) -> String {
+    let constructors: String = cases
+        .iter()
+        .map(|case| {
+            let constructor = case_constructor_name(case);
+            let id = quoted_key(case, inflection);
+            let (params, value) = match &case.type_ {
+                TypeFieldsDefinition::Unit => (String::new(), id),
+                _ => (
+                    format!("content: {}", emit_case_type_name(case, name)),
+                    format!("{{ {id}: content }}"),
+                ),
+            };
+            format!(
+                r#"
+    export function {constructor}({params}): {name} {{
+        return {value};
+    }}
+"#
+            )
+        })
+        .collect();
+
+    format!(
+        r#"
+export namespace {name} {{
+{constructors}}}
+"#
+    )
+}
+
+/// Emits `match{name}`, which calls the handler of the case of a value
+///
+/// Unit cases are matched by value first, which narrows the value to the object holding
+/// the content of the other cases
+fn emit_match_fn(name: &str, cases: &[EnumCase], inflection: Inflection) -> String {
+    let mut handlers = String::new();
+    let mut unit_branches = String::new();
+    let mut member_branches = String::new();
+    for case in cases {
+        let key = case_handler_key(case);
+        let id = quoted_key(case, inflection);
+        match &case.type_ {
+            TypeFieldsDefinition::Unit => {
+                handlers.push_str(&format!("        {key}: () => R,\n"));
+                unit_branches.push_str(&format!(
+                    r#"
+    if (value === {id}) {{
+        return handlers.{key}();
+    }}"#
+                ));
+            }
+            _ => {
+                let case_type = emit_case_type_name(case, name);
+                handlers.push_str(&format!("        {key}: (value: {case_type}) => R,\n"));
+                member_branches.push_str(&format!(
+                    r#"
+    if (value[{id}] !== undefined) {{
+        return handlers.{key}(value[{id}]);
+    }}"#
+                ));
+            }
+        }
     }
+
+    format!(
+        r#"
+export function match{name}<R>(
+    value: {name},
+    handlers: {{
+{handlers}    }},
+): R {{
+    {unit_branches}
+    {member_branches}
+    throw new Error(`unknown variant: ${{JSON.stringify(value)}}`);
+}}
+"#
+    )
 }
 
 fn emit_unit_case(case: &EnumCase, inflection: Inflection) -> String {
-    let name = &case.name.inflect(inflection);
+    let name = &case.serialized_name(inflection);
     ts_string! { # "'#name'" }
 }
 
 fn emit_member_case(case: &EnumCase, parent_name: &str, inflection: Inflection) -> String {
-    let name = &case.name.inflect(inflection);
+    let name = quoted_key(case, inflection);
     let member_type = emit_case_type_name(case, parent_name);
-    ts_string! { #name ? : #member_type }
+    format!("{name}?: {member_type}")
+}
+
+/// The serialized name of a case as a string literal, since it's not always a valid
+/// identifier, i.e. with `#[serde(rename_all = "kebab-case")]`
+fn quoted_key(case: &EnumCase, inflection: Inflection) -> String {
+    serde_json::to_string(&case.serialized_name(inflection)).expect("a string always serializes")
 }
 
 pub fn emit_case_type_name(case: &EnumCase, parent_name: &str) -> String {
@@ -130,7 +232,9 @@ fn emit_case_type(case: &EnumCase, parent_name: &str) -> String {
         return "".to_string();
     }
 
+    let docs = jsdoc(&case.docs);
     ts_string! {
+        #docs
         export type #name = #contents;
     }
 }
@@ -1,19 +1,21 @@
-use crate::AliasType;
+use crate::{json_literal, AliasType};
 
-use super::to_zod_type;
+use super::{default, describe, to_zod_type};
 
 pub fn emit_alias_type<T>() -> String
 where
     T: AliasType,
 {
+    let schema = to_zod_type(&T::source_type());
     format!(
         r#"
 
-export const {name}Schema = {schema};
+export const {name}Schema = {schema}{default}{describe};
 export type {name} = z.infer<typeof {name}Schema>;
 
 "#,
         name = T::name(),
-        schema = to_zod_type(&T::source_type())
+        default = default(T::default_value().map(|value| json_literal(&T::source_type(), value))),
+        describe = describe(&T::docs())
     )
 }
@@ -1,8 +1,8 @@
-use type_reflect_core::{EnumCase, EnumType, Inflectable, Inflection};
+use type_reflect_core::{EnumCase, EnumType, Inflection};
 
-use crate::EnumReflectionType;
+use crate::{enum_default_literal, EnumReflectionType};
 
-use super::to_zod_type;
+use super::{default, describe, to_zod_field_type, to_zod_type};
 
 pub fn emit_enum_type<T>() -> String
 where
@@ -10,6 +10,7 @@ where
 {
     match T::enum_type() {
         EnumType::Simple => emit_simple_enum_type::<T>(),
+        EnumType::Numeric => emit_numeric_enum_type::<T>(),
         EnumType::Complex {
             case_key,
             content_key,
@@ -26,21 +27,69 @@ where
         .into_iter()
         .map(|case| {
             format!(
-                r#"    {name} = "{name}",
+                r#"    {name} = "{serialized}",
 "#,
-                name = case.name
+                name = case.name,
+                serialized = case.serialized_name(T::inflection())
             )
         })
         .collect();
 
     let schema_name = T::union_schema_name();
-    let schema_cases: String = T::cases()
+    let schema_cases = |indent: &str| -> String {
+        T::cases()
+            .into_iter()
+            .map(|case| {
+                format!(
+                    "{indent}{enum_name}.{case_name},\n",
+                    enum_name = T::name(),
+                    case_name = case.name
+                )
+            })
+            .collect()
+    };
+
+    // Unrecognized strings are parsed as the fallback case
+    let schema = match T::cases().into_iter().find(|case| case.fallback) {
+        Some(case) => format!(
+            r#"z.union([
+    z.enum([
+{schema_cases}    ]),
+    z.string().transform((): {name} => {name}.{case}),
+])"#,
+            schema_cases = schema_cases("        "),
+            name = T::name(),
+            case = case.name
+        ),
+        None => format!("z.enum([\n{schema_cases}])", schema_cases = schema_cases("    ")),
+    };
+
+    format!(
+        r#"
+export enum {name} {{
+{simple_cases}}}
+
+export const {schema_name} = {schema}{default}{describe}
+"#,
+        default = default(enum_default_literal::<T>()),
+        describe = describe(&T::docs()),
+        name = T::name(),
+        simple_cases = simple_cases,
+        schema_name = schema_name,
+    )
+}
+
+fn emit_numeric_enum_type<T>() -> String
+where
+    T: EnumReflectionType,
+{
+    let numeric_cases: String = T::cases()
         .into_iter()
         .map(|case| {
             format!(
-                "    {enum_name}.{case_name},\n",
-                enum_name = T::name(),
-                case_name = case.name
+                "    {name} = {discriminant},\n",
+                name = case.name,
+                discriminant = case.discriminant.unwrap_or_default()
             )
         })
         .collect();
@@ -48,15 +97,14 @@ where
     format!(
         r#"
 export enum {name} {{
-{simple_cases}}}
+{numeric_cases}}}
 
-export const {schema_name} = z.enum([
-{schema_cases}])
+export const {schema_name} = z.nativeEnum({name}){default}{describe}
 "#,
+        default = default(enum_default_literal::<T>()),
+        describe = describe(&T::docs()),
         name = T::name(),
-        simple_cases = simple_cases,
-        schema_name = schema_name,
-        schema_cases = schema_cases
+        schema_name = T::union_schema_name(),
     )
 }
 
@@ -64,9 +112,15 @@ fn emit_complex_enum_type<T>(case_key: &String, content_key: &Option<String>) ->
 where
     T: EnumReflectionType,
 {
+    // The tag and content keys aren't always valid identifiers, i.e. with
+    // `#[serde(tag = "@type")]`, so they're emitted as string literals
+    let case_key = &serde_json::to_string(case_key).expect("a string always serializes");
+    let content_key = &content_key
+        .as_ref()
+        .map(|key| serde_json::to_string(key).expect("a string always serializes"));
     let cases_enum = T::generate_cases_enum();
     let union_types = T::generate_union_types(case_key, content_key, T::inflection());
-    let union_type = T::generate_union_schema();
+    let union_type = T::generate_union_schema(case_key);
 
     // Generate case type
 
@@ -96,7 +150,14 @@ trait EnumTypeBridge: EnumReflectionType {
     fn generate_cases_enum() -> String {
         let mut case_values = String::new();
         for case in Self::cases() {
-            case_values.push_str(format!(r#"    {name} = "{name}""#, name = case.name).as_str());
+            case_values.push_str(
+                format!(
+                    r#"    {name} = "{serialized}""#,
+                    name = case.name,
+                    serialized = case.serialized_name(Self::inflection())
+                )
+                .as_str(),
+            );
             case_values.push_str(",\n");
         }
 
@@ -140,9 +201,23 @@ export enum {name} {{
             type_reflect_core::TypeFieldsDefinition::Tuple(inner) => {
                 let content_key = match content_key {
                     Some(content_key) => content_key,
+                    // An internally tagged newtype variant merges the fields of its content with the tag
+                    None if inner.len() == 1 => {
+                        return format!(
+                            r#"
+export const {schema_name} = {type_}.extend({{
+    {case_key}: z.literal({id}),
+}}){describe};
+export type {name} = z.infer<typeof {schema_name}>
+            "#,
+                            type_ = to_zod_type(&inner[0]),
+                            describe = describe(&case.docs),
+                            name = format!("{}Case{}", Self::name(), case.name),
+                        )
+                    }
                     None => {
                         //TODO: make this a localized Syn error
-                        panic!("Content key required on enums containing at least one tuple-type variant.")
+                        panic!("Content key required on enums containing at least one tuple-type variant with more than one field.")
                     }
                 };
                 if inner.len() == 1 {
@@ -171,9 +246,10 @@ export enum {name} {{
                     .iter()
                     .map(|item| {
                         format!(
-                            "    {}: {},\n",
-                            item.name.inflect(case.inflection),
-                            to_zod_type(&item.type_)
+                            "    {}: {}{},\n",
+                            item.serialized_name(case.inflection),
+                            to_zod_field_type(item),
+                            describe(&item.docs)
                         )
                     })
                     .collect();
@@ -193,9 +269,10 @@ export enum {name} {{
             r#"
 export const {schema_name} = z.object({{
     {case_key}: z.literal({id}),
-{additional_fields}}});
+{additional_fields}}}){describe};
 export type {name} = z.infer<typeof {schema_name}>
             "#,
+            describe = describe(&case.docs),
             schema_name = schema_name,
             name = format!("{}Case{}", Self::name(), case.name),
             case_key = case_key,
@@ -208,7 +285,7 @@ export type {name} = z.infer<typeof {schema_name}>
         format!("{}Schema", Self::name())
     }
 
-    fn generate_union_schema() -> String {
+    fn generate_union_schema(case_key: &str) -> String {
         let schema_name = Self::union_schema_name();
         let mut cases = String::new();
 
@@ -216,12 +293,26 @@ export type {name} = z.infer<typeof {schema_name}>
             cases.push_str(format!("    {},\n", union_type_name(&case, Self::name())).as_str());
         }
 
+        // Unrecognized variants are parsed as the fallback case, discarding their content like serde does
+        if let Some(case) = Self::cases().into_iter().find(|case| case.fallback) {
+            cases.push_str(&format!(
+                r#"    z.object({{
+        {case_key}: z.string().refine((key) => !Object.values({case_type}).includes(key as {case_type})),
+    }}).transform(() => ({{ {case_key}: {id} as const }})),
+"#,
+                case_type = Self::case_type_name(),
+                id = Self::case_id(&case),
+            ));
+        }
+
         format!(
             r#"
 export const {schema_name} = z.union([
-{cases}]);
+{cases}]){default}{describe};
 export type {name} = z.infer<typeof {schema_name}>
             "#,
+            default = default(enum_default_literal::<Self>()),
+            describe = describe(&Self::docs()),
             cases = cases,
             schema_name = schema_name,
             name = Self::name()
@@ -13,8 +13,17 @@ use enum_type::*;
 mod alias_type;
 use alias_type::*;
 
+use crate::type_script::to_ts_type;
+
 #[derive(Default)]
-pub struct Zod {}
+pub struct Zod {
+    /// Also emit `FooInput` and `FooOutput` schemas for structs which
+    /// are serialized differently than they're deserialized
+    pub io_types: bool,
+    /// How `Option` fields are represented, unless overridden
+    /// with `#[reflect(option_style = "..")]`
+    pub option_style: OptionStyle,
+}
 
 pub trait TypeExporter {
     fn export<T>() -> String;
@@ -23,19 +32,174 @@ pub trait TypeExporter {
 fn to_zod_type(t: &Type) -> String {
     match t {
         // TODO: support generics
-        Type::Named(t) => format!("{}Schema", t.name),
+        Type::Named(t) => type_override(&t.name)
+            .and_then(|overrides| overrides.zod)
+            .unwrap_or_else(|| format!("{}Schema", export_name(&t.name))),
         Type::String => "z.string()".to_string(),
+        Type::FormattedString(format) => to_zod_string_format(format),
         Type::Int => "z.number()".to_string(),
         Type::UnsignedInt => "z.number()".to_string(),
         Type::Float => "z.number()".to_string(),
         Type::Boolean => "z.bool()".to_string(),
-        Type::Option(t) => format!("{}.optional()", to_zod_type(t)),
+        Type::Null => "z.null()".to_string(),
+        // Only a field can be omitted, so any other `None` is `null`
+        Type::Option(t) => format!("{}.nullable()", to_zod_type(t)),
         Type::Array(t) => format!("z.array({})", to_zod_type(t)),
         Type::Map { key, value } => format!("z.map({}, {})", to_zod_type(key), to_zod_type(value)),
-        Type::Transparent(_t) => unimplemented!("Transparent types not yet implemented for Zod"),
+        Type::Transparent(t) => to_zod_type(&t.type_),
+        Type::Override { type_, overrides } => match &overrides.zod {
+            Some(zod) => zod.clone(),
+            None => to_zod_type(type_),
+        },
+        Type::Constrained { type_, constraints } => {
+            format!(
+                "{}{}",
+                to_zod_type(type_),
+                to_zod_constraints(constraints, is_string(type_))
+            )
+        }
+        Type::Inline(inline) => match &inline.fields {
+            TypeFieldsDefinition::Unit => "z.null()".to_string(),
+            TypeFieldsDefinition::Tuple(tuple) => {
+                let items: Vec<String> = tuple.iter().map(to_zod_type).collect();
+                format!("z.tuple([{}])", items.join(", "))
+            }
+            TypeFieldsDefinition::Named(named) => {
+                format!("z.object({{ {} }})", named_fields(named, inline.inflection))
+            }
+        },
+    }
+}
+
+/// Renders the schema of a field, which honors the field's `option_style`
+fn to_zod_field_type(field: &NamedField) -> String {
+    match &field.type_ {
+        Type::Option(t) => format!(
+            "{}{}",
+            to_zod_type(t),
+            option_modifier(option_style(field.option_style))
+        ),
+        t => to_zod_type(t),
+    }
+}
+
+fn option_modifier(style: OptionStyle) -> &'static str {
+    match style {
+        OptionStyle::Optional => ".optional()",
+        OptionStyle::Nullable => ".nullable()",
+        OptionStyle::Nullish => ".nullish()",
+    }
+}
+
+/// Renders the description as a `.describe()` call to be appended to a schema,
+/// or an empty string if there is no description.
+fn describe(docs: &Documentation) -> String {
+    match &docs.description {
+        Some(description) => format!(
+            ".describe({})",
+            serde_json::to_string(description).expect("a string always serializes")
+        ),
+        None => String::new(),
     }
 }
 
+/// Renders the `#[reflect(default)]` value as a `.default()` call to be appended to a schema,
+/// or an empty string if there is no default value.
+fn default(value: Option<String>) -> String {
+    match value {
+        Some(value) => format!(".default({value})"),
+        None => String::new(),
+    }
+}
+
+/// Whether the type is serialized as a string
+fn is_string(type_: &Type) -> bool {
+    match type_ {
+        Type::String | Type::FormattedString(_) => true,
+        Type::Transparent(t) => is_string(&t.type_),
+        _ => false,
+    }
+}
+
+fn to_zod_constraints(constraints: &Constraints, is_string: bool) -> String {
+    let mut result = String::new();
+    // Zod counts the length of a string in UTF-16 code units, so a string's length is
+    // refined in characters instead, like `TSValidation` and the `validator` crate
+    if !is_string {
+        if let Some(min_len) = constraints.min_len {
+            result.push_str(&format!(".min({min_len})"));
+        }
+        if let Some(max_len) = constraints.max_len {
+            result.push_str(&format!(".max({max_len})"));
+        }
+    }
+    if let Some(min) = constraints.min {
+        result.push_str(&format!(".gte({min})"));
+    }
+    if let Some(max) = constraints.max {
+        result.push_str(&format!(".lte({max})"));
+    }
+    if let Some(pattern) = &constraints.pattern {
+        let pattern = serde_json::to_string(pattern).expect("a string always serializes");
+        result.push_str(&format!(".regex(new RegExp({pattern}))"));
+    }
+    if constraints.email {
+        result.push_str(".email()");
+    }
+    if constraints.url {
+        result.push_str(".url()");
+    }
+    // A refinement ends the string methods, so the length checks go last
+    if is_string {
+        if let Some(min_len) = constraints.min_len {
+            result.push_str(&format!(
+                r#".refine((value) => [...value].length >= {min_len}, {{ message: "expected length of at least {min_len}" }})"#
+            ));
+        }
+        if let Some(max_len) = constraints.max_len {
+            result.push_str(&format!(
+                r#".refine((value) => [...value].length <= {max_len}, {{ message: "expected length of at most {max_len}" }})"#
+            ));
+        }
+    }
+    result
+}
+
+fn to_zod_string_format(format: &StringFormat) -> String {
+    match format {
+        StringFormat::DateTime => "z.string().datetime({ offset: true })".to_string(),
+        StringFormat::NaiveDateTime => "z.string().datetime({ local: true })".to_string(),
+        StringFormat::Date => "z.string().date()".to_string(),
+        StringFormat::Time => "z.string().time()".to_string(),
+        StringFormat::Uuid => "z.string().uuid()".to_string(),
+        StringFormat::Url => "z.string().url()".to_string(),
+        StringFormat::Decimal => format!("z.string().regex(/{}/)", format.pattern()),
+    }
+}
+
+fn struct_schema(
+    name: &str,
+    fields: &TypeFieldsDefinition,
+    inflection: Inflection,
+    default: &str,
+    describe: &str,
+) -> String {
+    let schema = match fields {
+        // A unit struct is serialized as `null`
+        TypeFieldsDefinition::Unit => "z.null()".to_string(),
+        _ => format!("z.object({{\n{}}})", struct_fields(fields, inflection)),
+    };
+    format!(
+        r#"
+
+export const {name}Schema = {schema}{default}{describe};
+
+export type {name} = z.infer<typeof {name}Schema>;
+
+"#
+    )
+}
+
 impl TypeEmitter for Zod {
     fn prefix(&mut self) -> String {
         "import { z } from 'zod';\n".to_string()
@@ -45,35 +209,57 @@ impl TypeEmitter for Zod {
     where
         T: StructType,
     {
-        let members = struct_fields(&T::fields(), T::inflection());
-        let name = T::name();
-
-        format!(
-            r#"
+        with_option_style(self.option_style, || {
+            let name = T::name();
+            let fields = T::fields();
+            let describe = describe(&T::docs());
+            let default = default(
+                T::default_value().map(|value| Definition::of_struct::<T>().literal(value)),
+            );
 
-export const {name}Schema = z.object({{
-{members}}});
-
-export type {name} = z.infer<typeof {name}Schema>;
-
-"#,
-            members = members,
-            name = name
-        )
+            let mut result = struct_schema(name, &fields, T::inflection(), &default, &describe);
+            if self.io_types && fields.is_directional() {
+                for (suffix, direction) in [
+                    ("Input", Direction::Deserialize),
+                    ("Output", Direction::Serialize),
+                ] {
+                    result.push_str(&struct_schema(
+                        &format!("{name}{suffix}"),
+                        &fields.in_direction(direction),
+                        T::inflection(),
+                        "",
+                        &describe,
+                    ));
+                }
+            }
+            result
+        })
     }
 
     fn emit_enum<T>(&mut self) -> String
     where
         T: EnumReflectionType,
     {
-        emit_enum_type::<T>()
+        with_option_style(self.option_style, emit_enum_type::<T>)
     }
 
     fn emit_alias<T>(&mut self) -> String
     where
         T: AliasType,
     {
-        emit_alias_type::<T>()
+        with_option_style(self.option_style, emit_alias_type::<T>)
+    }
+
+    fn emit_value(&mut self, name: &str, type_: &Type, value: &serde_json::Value) -> String {
+        // The types inferred from the schemas share their names with the reflected types,
+        // so the TypeScript type can be used as the annotation
+        with_option_style(self.option_style, || {
+            format!(
+                "\nexport const {name}: {type_} = {value};\n",
+                type_ = to_ts_type(type_),
+                value = json_literal(type_, value.clone())
+            )
+        })
     }
 
     fn finalize<P>(&mut self, _path: P) -> Result<(), std::io::Error>
@@ -1,10 +1,10 @@
-use crate::zod::to_zod_type;
+use crate::zod::{describe, to_zod_field_type};
 use ts_quote::*;
-use type_reflect_core::{Inflectable, Inflection, NamedField, TypeFieldsDefinition};
+use type_reflect_core::{Inflection, NamedField, TypeFieldsDefinition};
 
 pub fn struct_member(member: &NamedField, inflection: Inflection) -> String {
-    let name = &member.name.inflect(inflection);
-    let value = to_zod_type(&member.type_);
+    let name = &member.serialized_name(inflection);
+    let value = format!("{}{}", to_zod_field_type(member), describe(&member.docs));
     ts_string! { #name: #value, }
 
     // format!("    {name}: {value},\n", name = name, value = value)
@@ -20,7 +20,7 @@ pub fn named_fields(fields: &Vec<NamedField>, inflection: Inflection) -> String
 
 pub fn struct_fields(fields: &TypeFieldsDefinition, inflection: Inflection) -> String {
     match fields {
-        TypeFieldsDefinition::Unit => todo!(),
+        TypeFieldsDefinition::Unit => String::new(),
         TypeFieldsDefinition::Tuple(_) => todo!(),
         TypeFieldsDefinition::Named(named) => named_fields(named, inflection),
     }
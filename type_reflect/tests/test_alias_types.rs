@@ -0,0 +1,150 @@
+mod common;
+
+use anyhow::Result;
+use common::*;
+
+use serde::{Deserialize, Serialize};
+use type_reflect::*;
+
+/// The `Reflect` derive can't be applied to a `type` item,
+/// so these aliases implement `AliasType` directly
+pub struct Tags;
+
+impl Emittable for Tags {
+    fn emit_with<E: TypeEmitter>(emitter: &mut E) -> String {
+        emitter.emit_alias::<Self>()
+    }
+}
+
+impl AliasType for Tags {
+    fn name() -> &'static str {
+        "Tags"
+    }
+    fn source_type() -> Type {
+        Type::Array(Box::new(Type::String))
+    }
+    fn rust() -> String {
+        "pub type Tags = Vec<String>;".to_string()
+    }
+}
+
+pub struct Nickname;
+
+impl Emittable for Nickname {
+    fn emit_with<E: TypeEmitter>(emitter: &mut E) -> String {
+        emitter.emit_alias::<Self>()
+    }
+}
+
+impl AliasType for Nickname {
+    fn name() -> &'static str {
+        "Nickname"
+    }
+    fn source_type() -> Type {
+        Type::Option(Box::new(Type::String))
+    }
+    fn rust() -> String {
+        "pub type Nickname = Option<String>;".to_string()
+    }
+}
+
+#[derive(Reflect, Serialize, Deserialize)]
+pub struct Heartbeat;
+
+#[derive(Reflect, Serialize, Deserialize)]
+pub struct Profile {
+    pub tags: Vec<String>,
+    pub nickname: Option<String>,
+    pub heartbeat: Heartbeat,
+}
+
+pub const SCOPE: &'static str = "test_alias_types";
+
+#[test]
+fn test_unit_struct_serialization() -> Result<()> {
+    assert_eq!(serde_json::to_string(&Heartbeat)?, "null");
+    assert!(matches!(Heartbeat::fields(), TypeFieldsDefinition::Unit));
+    Ok(())
+}
+
+#[test]
+fn test_validation() -> Result<()> {
+    let output = init_path(SCOPE, "test_validation");
+
+    export_types!(
+        types: [ Tags, Nickname, Heartbeat, Profile ],
+        destinations: [(
+            output.ts_path(),
+            emitters: [
+                TypeScript(),
+                TSValidation(),
+                TSFormat(
+                    tab_size: 2,
+                    line_width: 80,
+                ),
+            ],
+        )]
+    )?;
+
+    output.write_jest(
+        "Tags, Nickname, Heartbeat, Profile",
+        r#"
+
+describe('Alias and Unit Struct Validation', ()=>{
+
+  it("validates an alias of an array", ()=>{
+    expect(Tags.validate(['a', 'b'])).toEqual(['a', 'b']);
+    const result = Tags.safeValidate(['a', 2]);
+    expect(result.ok).toBe(false);
+    if (!result.ok) {
+        expect(result.errors[0].path).toEqual('/1');
+    }
+  });
+
+  it("validates an alias of an option", ()=>{
+    expect(() => Nickname.validate('Ada')).not.toThrow();
+    expect(() => Nickname.validate(null)).not.toThrow();
+    expect(() => Nickname.validate(7)).toThrow();
+  });
+
+  it("validates a unit struct as null", ()=>{
+    expect(Heartbeat.validate(null)).toBeNull();
+    expect(() => Heartbeat.validate({})).toThrow();
+  });
+
+  it("validates a struct with a unit struct field", ()=>{
+    expect(() => Profile.validate({ tags: [], heartbeat: null })).not.toThrow();
+    const result = Profile.safeValidate({ tags: [], heartbeat: 1 });
+    expect(result.ok).toBe(false);
+    if (!result.ok) {
+        expect(result.errors[0].path).toEqual('/heartbeat');
+    }
+  });
+
+})
+    "#,
+    )?;
+
+    output.run_ts()
+}
+
+#[test]
+fn test_zod() -> Result<()> {
+    let output = init_path(SCOPE, "test_zod");
+
+    export_types!(
+        types: [ Tags, Nickname, Heartbeat ],
+        destinations: [(
+            output.ts_path(),
+            emitters: [
+                Zod(),
+            ],
+        )]
+    )?;
+
+    let zod = std::fs::read_to_string(output.ts_path())?;
+    assert!(zod.contains("export const NicknameSchema = z.string().nullable()"));
+    assert!(zod.contains("export const HeartbeatSchema = z.null()"));
+
+    Ok(())
+}
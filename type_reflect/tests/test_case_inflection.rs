@@ -297,4 +297,58 @@ mod untagged {
 
         Ok(())
     }
+
+    #[derive(Serialize, Deserialize, Reflect)]
+    #[serde(rename_all = "kebab-case")]
+    pub enum Command {
+        MoveTo { x: f32, y: f32 },
+        ScaleBy(f32),
+        ResetAll,
+    }
+
+    #[cfg(feature = "testing")]
+    #[test]
+    fn test_untagged_kebab() -> Result<()> {
+        use type_reflect::testing::Runtime;
+
+        let output = init_path(SCOPE, "test_untagged_kebab");
+
+        export_types!(
+            types: [ Command ],
+            destinations: [(
+                output.ts_path(),
+                emitters: [
+                    TypeScript(),
+                    TSValidation(),
+                ],
+            )]
+        )?;
+
+        let mut runtime = Runtime::load(output.ts_path())?;
+        runtime.assert_valid(&Command::MoveTo { x: 1.0, y: 2.0 });
+        runtime.assert_valid(&Command::ScaleBy(2.0));
+        runtime.assert_valid(&Command::ResetAll);
+        runtime.assert_invalid::<Command>(r#"{ "scale-by": "twice" }"#);
+        runtime.assert_invalid::<Command>(r#"{ "move-to": { "x": 1 } }"#);
+
+        let handlers = "{ moveTo: (to) => `move ${to.x}`, scaleBy: (by) => `scale ${by}`, resetAll: () => 'reset' }";
+        assert_eq!(
+            runtime.eval(&format!("matchCommand(Command.scaleBy(2), {handlers})")),
+            Ok("\"scale 2\"".to_string())
+        );
+        assert_eq!(
+            runtime.eval(&format!("matchCommand(Command.moveTo({{ x: 1, y: 2 }}), {handlers})")),
+            Ok("\"move 1\"".to_string())
+        );
+        assert_eq!(
+            runtime.eval(&format!("matchCommand(Command.resetAll(), {handlers})")),
+            Ok("\"reset\"".to_string())
+        );
+        assert_eq!(
+            runtime.eval(r#"JSON.stringify(Command.scaleBy(2))"#),
+            Ok(r#""{\"scale-by\":2}""#.to_string())
+        );
+
+        Ok(())
+    }
 }
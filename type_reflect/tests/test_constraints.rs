@@ -0,0 +1,206 @@
+mod common;
+
+use anyhow::Result;
+use common::*;
+
+use serde::{Deserialize, Serialize};
+use type_reflect::*;
+use validator::Validate;
+
+#[derive(Reflect, Validate, Serialize, Deserialize)]
+pub struct SignUp {
+    #[reflect(min_len = 1, max_len = 20, pattern = "^[a-z0-9_]+$")]
+    pub username: String,
+    #[validate(email)]
+    pub email: String,
+    #[validate(range(min = 13, max = 130))]
+    pub age: u32,
+    #[validate(length(min = 1))]
+    pub interests: Vec<String>,
+    #[reflect(max_len = 200)]
+    pub bio: Option<String>,
+}
+
+#[derive(Reflect, Validate, Serialize, Deserialize)]
+pub struct Badge {
+    #[validate(length(min = 2, max = 3))]
+    pub emoji: String,
+}
+
+pub const SCOPE: &'static str = "test_constraints";
+
+#[test]
+fn test_validation() -> Result<()> {
+    let output = init_path(SCOPE, "test_validation");
+
+    export_types!(
+        types: [ SignUp ],
+        destinations: [(
+            output.ts_path(),
+            emitters: [
+                TypeScript(),
+                TSValidation(),
+                TSFormat(
+                    tab_size: 2,
+                    line_width: 80,
+                ),
+            ],
+        )]
+    )?;
+
+    output.write_jest(
+        "SignUp",
+        r#"
+
+const valid = {
+    username: 'ada_l',
+    email: 'ada@example.com',
+    age: 36,
+    interests: ['engines'],
+};
+
+describe('Constraint Validation', ()=>{
+
+  it("validates an object satisfying the constraints", ()=>{
+    expect(() => {
+        SignUp.validate(valid)
+    }).not.toThrow();
+  });
+
+  it("throws an error validating an empty username", ()=>{
+    expect(() => {
+        SignUp.validate({...valid, username: ''})
+    }).toThrow();
+  });
+
+  it("throws an error validating a username not matching the pattern", ()=>{
+    expect(() => {
+        SignUp.validate({...valid, username: 'Ada Lovelace'})
+    }).toThrow();
+  });
+
+  it("throws an error validating a malformed email", ()=>{
+    expect(() => {
+        SignUp.validate({...valid, email: 'ada'})
+    }).toThrow();
+  });
+
+  it("throws an error validating an age out of range", ()=>{
+    expect(() => {
+        SignUp.validate({...valid, age: 12})
+    }).toThrow();
+  });
+
+  it("throws an error validating empty interests", ()=>{
+    expect(() => {
+        SignUp.validate({...valid, interests: []})
+    }).toThrow();
+  });
+
+  it("throws an error validating an optional bio which is too long", ()=>{
+    expect(() => {
+        SignUp.validate({...valid, bio: 'a'.repeat(201)})
+    }).toThrow();
+  });
+
+})
+    "#,
+    )?;
+
+    output.run_ts()
+}
+
+#[test]
+fn test_zod_constraints() -> Result<()> {
+    let output = init_path(SCOPE, "test_zod_constraints");
+
+    export_types!(
+        types: [ SignUp ],
+        destinations: [(
+            output.ts_path(),
+            emitters: [
+                Zod(),
+            ],
+        )]
+    )?;
+
+    let zod = std::fs::read_to_string(output.ts_path())?;
+    assert!(zod.contains(r#"z.string().regex(new RegExp("^[a-z0-9_]+$")).refine((value) => [...value].length >= 1, { message: "expected length of at least 1" }).refine((value) => [...value].length <= 20, { message: "expected length of at most 20" })"#));
+    assert!(zod.contains("z.string().email()"));
+    assert!(zod.contains("z.number().gte(13).lte(130)"));
+    assert!(zod.contains("z.array(z.string()).min(1)"));
+    assert!(zod.contains(r#"z.string().refine((value) => [...value].length <= 200, { message: "expected length of at most 200" }).optional()"#));
+
+    Ok(())
+}
+
+#[cfg(feature = "testing")]
+#[test]
+fn test_surrogate_pairs() -> Result<()> {
+    use type_reflect::testing::Runtime;
+
+    let output = init_path(SCOPE, "test_surrogate_pairs");
+
+    export_types!(
+        types: [ Badge ],
+        destinations: [(
+            output.ts_path(),
+            emitters: [
+                TypeScript(),
+                TSValidation(),
+            ],
+        )]
+    )?;
+
+    let mut runtime = Runtime::load(output.ts_path())?;
+    // Each crab is a surrogate pair, so it's two UTF-16 code units but one character
+    for (emoji, valid) in [("🦀", false), ("🦀🦀", true), ("🦀🦀🦀", true), ("🦀🦀🦀🦀", false)] {
+        let badge = Badge {
+            emoji: emoji.to_string(),
+        };
+        assert_eq!(badge.validate().is_ok(), valid);
+        match valid {
+            true => runtime.assert_valid(&badge),
+            false => runtime.assert_invalid::<Badge>(&serde_json::to_string(&badge)?),
+        }
+    }
+
+    Ok(())
+}
+
+#[cfg(feature = "testing")]
+#[test]
+fn test_zod_surrogate_pairs() -> Result<()> {
+    use type_reflect::testing::Runtime;
+
+    let output = init_path(SCOPE, "test_zod_surrogate_pairs");
+
+    export_types!(
+        types: [ Badge ],
+        destinations: [(
+            output.ts_path(),
+            emitters: [
+                Zod(),
+            ],
+        )]
+    )?;
+
+    // Zod itself can't be loaded, so the length refinements are evaluated on their own
+    let zod = std::fs::read_to_string(output.ts_path())?;
+    let refinements: Vec<&str> = zod
+        .split(".refine(")
+        .skip(1)
+        .map(|refinement| refinement.split(", {").next().unwrap())
+        .collect();
+    assert_eq!(refinements.len(), 2);
+
+    let mut runtime = Runtime::from_source("export {};".to_string())?;
+    for (emoji, valid) in [("🦀", false), ("🦀🦀", true), ("🦀🦀🦀", true), ("🦀🦀🦀🦀", false)] {
+        let accepted = refinements.iter().all(|refinement| {
+            runtime.eval(&format!("({refinement})({emoji:?})")) == Ok("true".to_string())
+        });
+        assert_eq!(accepted, valid, "{emoji}");
+    }
+
+    Ok(())
+}
@@ -0,0 +1,176 @@
+mod common;
+
+use anyhow::Result;
+use common::*;
+
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use type_reflect::*;
+
+#[derive(Reflect, Default, Serialize, Deserialize)]
+#[reflect(default)]
+pub enum Theme {
+    #[default]
+    Light,
+    Dark,
+}
+
+#[derive(Reflect, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+#[reflect(default)]
+pub struct Settings {
+    pub page_size: u32,
+    pub show_hidden: bool,
+    pub tags: Vec<String>,
+    pub signature: Option<String>,
+}
+
+impl Default for Settings {
+    fn default() -> Self {
+        Self {
+            page_size: 25,
+            show_hidden: false,
+            tags: vec!["inbox".to_string()],
+            signature: None,
+        }
+    }
+}
+
+#[derive(Reflect, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+#[reflect(default)]
+pub struct Filters {
+    #[reflect(option_style = "nullable")]
+    pub archived: Option<bool>,
+    pub label: Option<String>,
+    pub limits: HashMap<String, Option<u32>>,
+}
+
+impl Default for Filters {
+    fn default() -> Self {
+        Self {
+            archived: None,
+            label: None,
+            limits: HashMap::from([("search".to_string(), None)]),
+        }
+    }
+}
+
+pub const SCOPE: &'static str = "test_default_values";
+
+#[test]
+fn test_default_value() {
+    assert_eq!(
+        Settings::default_value(),
+        Some(serde_json::json!({
+            "pageSize": 25,
+            "showHidden": false,
+            "tags": ["inbox"],
+            "signature": null,
+        }))
+    );
+    assert_eq!(<Theme as EnumReflectionType>::default_value(), Some(serde_json::json!("Light")));
+}
+
+#[test]
+fn test_typescript_defaults() -> Result<()> {
+    let output = init_path(SCOPE, "test_typescript_defaults");
+
+    export_types!(
+        types: [ Theme, Settings ],
+        destinations: [(
+            output.ts_path(),
+            emitters: [
+                TypeScript(),
+                TSValidation(),
+                TSFormat(
+                    tab_size: 2,
+                    line_width: 80,
+                ),
+            ],
+        )]
+    )?;
+
+    output.write_jest(
+        "Theme, Settings, defaultTheme, defaultSettings",
+        r#"
+
+describe('Default Values', ()=>{
+
+  it("exports the default value of a simple enum", ()=>{
+    expect(defaultTheme).toEqual(Theme.Light);
+  });
+
+  it("exports a default value which passes validation", ()=>{
+    expect(() => {
+        Settings.validate(defaultSettings)
+    }).not.toThrow();
+  });
+
+  it("exports the default value of a struct", ()=>{
+    expect(defaultSettings.pageSize).toEqual(25);
+    expect(defaultSettings.tags).toEqual(['inbox']);
+  });
+
+})
+    "#,
+    )?;
+
+    output.run_ts()
+}
+
+#[test]
+fn test_zod_defaults() -> Result<()> {
+    let output = init_path(SCOPE, "test_zod_defaults");
+
+    export_types!(
+        types: [ Theme, Settings ],
+        destinations: [(
+            output.ts_path(),
+            emitters: [
+                Zod(),
+            ],
+        )]
+    )?;
+
+    let zod = std::fs::read_to_string(output.ts_path())?;
+    assert!(zod.contains(".default(Theme.Light)"));
+    assert!(zod.contains(
+        r#".default({"pageSize":25,"showHidden":false,"tags":["inbox"]})"#
+    ));
+
+    Ok(())
+}
+
+#[test]
+fn test_default_nulls() -> Result<()> {
+    let output = init_path(SCOPE, "test_default_nulls");
+    let zod_output = init_path(SCOPE, "test_zod_default_nulls");
+
+    export_types!(
+        types: [ Filters ],
+        destinations: [
+            (
+                output.ts_path(),
+                emitters: [ TypeScript() ],
+            ),
+            (
+                zod_output.ts_path(),
+                emitters: [ Zod() ],
+            ),
+        ]
+    )?;
+
+    // Only the `null` of the optional `label` is omitted
+    let ts = std::fs::read_to_string(output.ts_path())?;
+    assert!(ts.contains(
+        r#"export const defaultFilters: Filters = {
+  "archived": null,
+  "limits": { "search": null },
+};"#
+    ));
+    let zod = std::fs::read_to_string(zod_output.ts_path())?;
+    assert!(zod.contains(r#".default({"archived":null,"limits":{"search":null}})"#));
+
+    Ok(())
+}
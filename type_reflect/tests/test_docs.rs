@@ -0,0 +1,129 @@
+mod common;
+
+use anyhow::Result;
+use common::*;
+
+use serde::{Deserialize, Serialize};
+use type_reflect::*;
+
+/// A registered user of the application
+#[derive(Reflect, Serialize, Deserialize)]
+pub struct User {
+    /// The display name shown to other users
+    pub name: String,
+    /// The user's age in years.
+    ///
+    /// Omitted if the user hasn't provided it.
+    pub age: Option<u32>,
+    /// Legacy identifier, kept for older clients
+    #[deprecated(note = "use `name` instead")]
+    pub handle: Option<String>,
+}
+
+/// The status of an account
+#[derive(Reflect, Serialize, Deserialize)]
+pub enum Status {
+    /// The account is in good standing
+    Active,
+    /// The account has been suspended by a moderator
+    Suspended,
+}
+
+/// A remote reference to a user
+#[derive(Reflect, Serialize, Deserialize)]
+#[serde(tag = "_case", content = "data")]
+pub enum UserRef {
+    /// A reference by the user's numeric id
+    Id(u32),
+    /// A reference by display name
+    Name {
+        /// The display name, which comment */ terminators can't escape
+        name: String,
+    },
+}
+
+pub const SCOPE: &'static str = "test_docs";
+
+#[test]
+fn test_validation() -> Result<()> {
+    let output = init_path(SCOPE, "test_validation");
+
+    export_types!(
+        types: [ User, Status, UserRef ],
+        destinations: [(
+            output.ts_path(),
+            emitters: [
+                TypeScript(),
+                TSValidation(),
+                TSFormat(
+                    tab_size: 2,
+                    line_width: 80,
+                ),
+            ],
+        )]
+    )?;
+
+    let ts = std::fs::read_to_string(output.ts_path())?;
+    assert!(ts.contains("A registered user of the application"));
+    assert!(ts.contains("The user's age in years."));
+    assert!(ts.contains("@deprecated use `name` instead"));
+    assert!(ts.contains("The account has been suspended by a moderator"));
+    assert!(ts.contains("A reference by the user's numeric id"));
+    assert!(ts.contains("comment *\\/ terminators"));
+
+    output.write_jest(
+        "User, Status, UserRef",
+        r#"
+
+describe('Documented Type Validation', ()=>{
+
+  it("validates an object: `{name: 'Ada', age: 36}`", ()=>{
+    expect(() => {
+        User.validate({name: 'Ada', age: 36})
+    }).not.toThrow();
+  });
+
+  it("validates an object: `Active`", ()=>{
+    expect(() => {
+        Status.validate(`Active`)
+    }).not.toThrow();
+  });
+
+  it("validates an object: `{_case: 'Name', data: {name: 'Ada'}}`", ()=>{
+    expect(() => {
+        UserRef.validate({_case: 'Name', data: {name: 'Ada'}})
+    }).not.toThrow();
+  });
+
+})
+    "#,
+    )?;
+
+    output.run_ts()
+}
+
+#[test]
+fn test_zod_describe() -> Result<()> {
+    let output = init_path(SCOPE, "test_zod_describe");
+
+    export_types!(
+        types: [ User, Status, UserRef ],
+        destinations: [(
+            output.ts_path(),
+            emitters: [
+                Zod(),
+            ],
+        )]
+    )?;
+
+    let zod = std::fs::read_to_string(output.ts_path())?;
+    assert!(zod.contains(r#"z.string().describe("The display name shown to other users")"#));
+    assert!(zod.contains(
+        r#".describe("The user's age in years.\n\nOmitted if the user hasn't provided it.")"#
+    ));
+    assert!(zod.contains(r#".describe("A registered user of the application")"#));
+    assert!(zod.contains(r#".describe("The status of an account")"#));
+    assert!(zod.contains(r#".describe("A reference by display name")"#));
+
+    Ok(())
+}
@@ -0,0 +1,69 @@
+#![cfg(feature = "testing")]
+
+mod common;
+
+use anyhow::Result;
+use common::*;
+
+use serde::{Deserialize, Serialize};
+use type_reflect::testing::Runtime;
+use type_reflect::*;
+
+#[derive(Reflect, Serialize, Deserialize)]
+pub struct User {
+    pub name: String,
+    pub age: Option<u32>,
+    pub tags: Vec<String>,
+}
+
+#[derive(Reflect, Serialize, Deserialize)]
+#[serde(tag = "_case", content = "data")]
+pub enum Shape {
+    Circle { radius: f32 },
+    Square(f32),
+    Empty,
+}
+
+pub const SCOPE: &'static str = "test_embedded_runtime";
+
+#[test]
+fn test_embedded_runtime() -> Result<()> {
+    let output = init_path(SCOPE, "test_embedded_runtime");
+
+    export_types!(
+        types: [ User, Shape ],
+        destinations: [(
+            output.ts_path(),
+            emitters: [
+                TypeScript(),
+                TSValidation(),
+            ],
+        )]
+    )?;
+
+    let mut runtime = Runtime::load(output.ts_path())?;
+
+    runtime.assert_valid(&User {
+        name: "Ada".to_string(),
+        age: Some(36),
+        tags: vec!["admin".to_string()],
+    });
+    runtime.assert_valid(&User {
+        name: "Grace".to_string(),
+        age: None,
+        tags: vec![],
+    });
+    runtime.assert_invalid::<User>(r#"{ "name": 7, "tags": [] }"#);
+    runtime.assert_invalid::<User>(r#"{ "name": "Ada", "tags": [1] }"#);
+
+    runtime.assert_valid(&Shape::Circle { radius: 1.5 });
+    runtime.assert_valid(&Shape::Square(2.0));
+    runtime.assert_valid(&Shape::Empty);
+    runtime.assert_invalid::<Shape>(r#"{ "_case": "Triangle" }"#);
+    runtime.assert_invalid::<Shape>(r#"{ "_case": "Square", "data": "wide" }"#);
+
+    assert_eq!(runtime.eval(r#"isUser({ name: "Ada", tags: [] })"#), Ok("true".to_string()));
+    assert!(!runtime.has_validator("Unknown"));
+
+    Ok(())
+}
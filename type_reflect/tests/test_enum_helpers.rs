@@ -0,0 +1,119 @@
+mod common;
+
+use anyhow::Result;
+use common::*;
+
+use serde::{Deserialize, Serialize};
+use type_reflect::*;
+
+#[derive(Reflect, Serialize, Deserialize)]
+#[serde(tag = "_case", content = "data")]
+pub enum Status {
+    Initial,
+    #[serde(rename_all = "camelCase")]
+    InProgress {
+        progress: f32,
+        should_convert: bool,
+    },
+    Complete {
+        urls: Vec<String>,
+    },
+}
+
+#[derive(Reflect, Serialize, Deserialize)]
+pub struct Point {
+    pub x: f32,
+    pub y: f32,
+}
+
+#[derive(Reflect, Serialize, Deserialize)]
+#[serde(tag = "type")]
+pub enum Marker {
+    Pin(Point),
+    Label { text: String },
+}
+
+#[derive(Reflect, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub enum Shape {
+    Circle { radius: f32 },
+    Scale(f32),
+    Null,
+}
+
+pub const SCOPE: &'static str = "test_enum_helpers";
+
+#[test]
+fn test_validation() -> Result<()> {
+    let output = init_path(SCOPE, "test_validation");
+
+    export_types!(
+        types: [ Status, Point, Marker, Shape ],
+        destinations: [(
+            output.ts_path(),
+            emitters: [
+                TypeScript(),
+                TSValidation(),
+                TSFormat(
+                    tab_size: 2,
+                    line_width: 80,
+                ),
+            ],
+        )]
+    )?;
+
+    output.write_jest(
+        "Status, matchStatus, Marker, matchMarker, Shape, matchShape",
+        r#"
+
+const describeStatus = (status: Status) => matchStatus(status, {
+    initial: () => 'initial',
+    inProgress: (value) => `${value.data.progress}`,
+    complete: (value) => value.data.urls.join(','),
+});
+
+describe('Enum Helpers', ()=>{
+
+  it("constructs adjacently tagged variants", ()=>{
+    expect(Status.initial()).toEqual({ _case: 'Initial' });
+    expect(Status.inProgress({ progress: 0.5, shouldConvert: true }))
+        .toEqual({ _case: 'InProgress', data: { progress: 0.5, shouldConvert: true } });
+    expect(() => Status.validate(Status.complete({ urls: ['a'] }))).not.toThrow();
+  });
+
+  it("matches adjacently tagged variants", ()=>{
+    expect(describeStatus(Status.initial())).toEqual('initial');
+    expect(describeStatus(Status.inProgress({ progress: 0.5, shouldConvert: false }))).toEqual('0.5');
+    expect(describeStatus(Status.complete({ urls: ['a', 'b'] }))).toEqual('a,b');
+  });
+
+  it("constructs and matches internally tagged variants", ()=>{
+    const pin = Marker.pin({ x: 1, y: 2 });
+    expect(pin).toEqual({ type: 'Pin', x: 1, y: 2 });
+    expect(Marker.label({ text: 'Home' })).toEqual({ type: 'Label', text: 'Home' });
+    expect(matchMarker(pin, {
+        pin: (value) => value.x + value.y,
+        label: () => 0,
+    })).toEqual(3);
+  });
+
+  it("constructs and matches externally tagged variants", ()=>{
+    expect(Shape.circle({ radius: 2 })).toEqual({ circle: { radius: 2 } });
+    expect(Shape.null_()).toEqual('null');
+
+    const area = (shape: Shape) => matchShape(shape, {
+        circle: (value) => value.radius * value.radius,
+        scale: (value) => value,
+        null: () => 0,
+    });
+    expect(area(Shape.circle({ radius: 2 }))).toEqual(4);
+    expect(area(Shape.scale(3))).toEqual(3);
+    expect(area(Shape.null_())).toEqual(0);
+  });
+
+})
+    "#,
+    )?;
+
+    output.run_ts()
+}
@@ -0,0 +1,135 @@
+mod common;
+
+use anyhow::Result;
+use common::*;
+
+use serde::{Deserialize, Serialize};
+use type_reflect::*;
+
+#[derive(Reflect, Serialize, Deserialize)]
+pub enum Platform {
+    Web,
+    Ios,
+    #[serde(other)]
+    Other,
+}
+
+#[derive(Reflect, Serialize, Deserialize)]
+#[reflect(non_exhaustive)]
+pub enum Channel {
+    Stable,
+    Beta,
+}
+
+#[derive(Reflect, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+#[reflect(non_exhaustive)]
+pub enum Region {
+    North,
+    South,
+}
+
+#[derive(Reflect, Serialize, Deserialize)]
+#[serde(tag = "type")]
+pub enum Notification {
+    Message { text: String },
+    Mention { user: String },
+    #[serde(other)]
+    Unsupported,
+}
+
+pub const SCOPE: &'static str = "test_fallback_variant";
+
+#[test]
+fn test_serde_other() -> Result<()> {
+    let notification: Notification = serde_json::from_str(r#"{"type":"Reaction","emoji":"+1"}"#)?;
+    assert!(matches!(notification, Notification::Unsupported));
+    assert!(Channel::cases().into_iter().any(|case| case.name == "Unknown" && case.fallback));
+
+    // The added case isn't renamed along with the others
+    let names: Vec<String> = Region::cases()
+        .into_iter()
+        .map(|case| case.serialized_name(Region::inflection()))
+        .collect();
+    assert_eq!(names, vec!["north", "south", "Unknown"]);
+    Ok(())
+}
+
+#[test]
+fn test_validation() -> Result<()> {
+    let output = init_path(SCOPE, "test_validation");
+
+    export_types!(
+        types: [ Platform, Channel, Notification ],
+        destinations: [(
+            output.ts_path(),
+            emitters: [
+                TypeScript(),
+                TSValidation(),
+                TSFormat(
+                    tab_size: 2,
+                    line_width: 80,
+                ),
+            ],
+        )]
+    )?;
+
+    output.write_jest(
+        "Platform, Channel, Notification",
+        r#"
+
+describe('Fallback Variant Validation', ()=>{
+
+  it("validates an unrecognized string as the serde(other) variant", ()=>{
+    expect(Platform.validate('Android')).toEqual(Platform.Other);
+  });
+
+  it("still throws an error validating a value which isn't a string", ()=>{
+    expect(() => {
+        Platform.validate(42)
+    }).toThrow();
+  });
+
+  it("validates an unrecognized string as the Unknown variant of a non_exhaustive enum", ()=>{
+    expect(Channel.validate('Nightly')).toEqual(Channel.Unknown);
+    expect(Channel.validate('Beta')).toEqual(Channel.Beta);
+  });
+
+  it("validates an unrecognized tag as the fallback variant", ()=>{
+    expect(Notification.validate({ type: 'Reaction', emoji: '+1' })).toEqual({ type: 'Unsupported' });
+  });
+
+  it("still throws an error validating a recognized tag with invalid content", ()=>{
+    expect(() => {
+        Notification.validate({ type: 'Mention' })
+    }).toThrow();
+  });
+
+})
+    "#,
+    )?;
+
+    output.run_ts()
+}
+
+#[test]
+fn test_zod_schema() -> Result<()> {
+    let output = init_path(SCOPE, "test_zod_schema");
+
+    export_types!(
+        types: [ Channel, Notification ],
+        destinations: [(
+            output.ts_path(),
+            emitters: [
+                Zod(),
+            ],
+        )]
+    )?;
+
+    let zod = std::fs::read_to_string(output.ts_path())?;
+    assert!(zod.contains("    z.enum([\n        Channel.Stable,\n        Channel.Beta,\n        Channel.Unknown,\n    ]),"));
+    assert!(zod.contains("z.string().transform((): Channel => Channel.Unknown)"));
+    assert!(zod.contains(r#".transform(() => ({ "type": NotificationCase.Unsupported as const }))"#));
+
+    Ok(())
+}
@@ -0,0 +1,196 @@
+mod common;
+
+use anyhow::Result;
+use common::*;
+
+use serde::{Deserialize, Serialize};
+use type_reflect::*;
+
+#[derive(Reflect, Serialize, Deserialize)]
+pub struct Circle {
+    pub radius: f64,
+}
+
+#[derive(Reflect, Serialize, Deserialize)]
+pub struct Rect {
+    pub width: f64,
+    pub height: f64,
+}
+
+#[derive(Reflect, Serialize, Deserialize)]
+#[serde(tag = "type")]
+pub enum Shape {
+    Circle(Circle),
+    Rect(Rect),
+    Point,
+}
+
+#[derive(Reflect, Serialize, Deserialize)]
+#[serde(tag = "@type")]
+pub enum Node {
+    Circle(Circle),
+    Group { children: Vec<String> },
+    #[serde(other)]
+    Unknown,
+}
+
+#[derive(Reflect, Serialize, Deserialize)]
+#[serde(tag = "@type", content = "@value")]
+pub enum Token {
+    Text(String),
+    Pair(u32, u32),
+    Span { start: u32 },
+    End,
+}
+
+pub const SCOPE: &'static str = "test_internally_tagged";
+
+#[test]
+fn test_validation() -> Result<()> {
+    let output = init_path(SCOPE, "test_validation");
+
+    export_types!(
+        types: [ Circle, Rect, Shape ],
+        destinations: [(
+            output.ts_path(),
+            emitters: [
+                TypeScript(),
+                TSValidation(),
+                TSFormat(
+                    tab_size: 2,
+                    line_width: 80,
+                ),
+            ],
+        )]
+    )?;
+
+    let circle = serde_json::to_string(&Shape::Circle(Circle { radius: 2.0 }))?;
+    assert_eq!(circle, r#"{"type":"Circle","radius":2.0}"#);
+
+    output.write_jest(
+        "Shape",
+        r#"
+
+describe('Internally Tagged Newtype Variant Validation', ()=>{
+
+  it("validates a newtype variant with the struct fields next to the tag", ()=>{
+    const shape: Shape = { type: "Circle", radius: 2 };
+    expect(() => {
+        Shape.validate(shape)
+    }).not.toThrow();
+  });
+
+  it("validates a unit variant", ()=>{
+    expect(() => {
+        Shape.validate({ type: 'Point' })
+    }).not.toThrow();
+  });
+
+  it("throws an error validating a newtype variant with invalid struct fields", ()=>{
+    expect(() => {
+        Shape.validate({ type: 'Rect', width: 2 })
+    }).toThrow();
+  });
+
+  it("throws an error validating the fields of another variant", ()=>{
+    expect(() => {
+        Shape.validate({ type: 'Rect', radius: 2 })
+    }).toThrow();
+  });
+
+})
+    "#,
+    )?;
+
+    output.run_ts()
+}
+
+#[test]
+fn test_zod_schema() -> Result<()> {
+    let output = init_path(SCOPE, "test_zod_schema");
+
+    export_types!(
+        types: [ Circle, Rect, Shape ],
+        destinations: [(
+            output.ts_path(),
+            emitters: [
+                Zod(),
+            ],
+        )]
+    )?;
+
+    let zod = std::fs::read_to_string(output.ts_path())?;
+    assert!(zod.contains("export const ShapeCaseCircleSchema = CircleSchema.extend({"));
+
+    Ok(())
+}
+
+#[cfg(feature = "testing")]
+#[test]
+fn test_quoted_tags() -> Result<()> {
+    use type_reflect::testing::Runtime;
+
+    let output = init_path(SCOPE, "test_quoted_tags");
+
+    export_types!(
+        types: [ Circle, Node, Token ],
+        destinations: [(
+            output.ts_path(),
+            emitters: [
+                TypeScript(),
+                TSValidation(strict: true),
+            ],
+        )]
+    )?;
+
+    let mut runtime = Runtime::load(output.ts_path())?;
+    runtime.assert_valid(&Node::Circle(Circle { radius: 2.0 }));
+    runtime.assert_valid(&Node::Group {
+        children: vec!["a".to_string()],
+    });
+    runtime.assert_invalid::<Node>(r#"{ "@type": "Group", "children": [1] }"#);
+    runtime.assert_invalid::<Node>(r#"{ "type": "Group", "children": [] }"#);
+    assert_eq!(
+        runtime.eval(r#"JSON.stringify(Node.validate({ "@type": "Other" }))"#),
+        Ok(r#""{\"@type\":\"Unknown\"}""#.to_string())
+    );
+    assert_eq!(
+        runtime.eval(r#"Node.isGroup({ "@type": "Group", children: [] })"#),
+        Ok("true".to_string())
+    );
+
+    runtime.assert_valid(&Token::Text("a".to_string()));
+    runtime.assert_valid(&Token::Pair(1, 2));
+    runtime.assert_valid(&Token::Span { start: 1 });
+    runtime.assert_valid(&Token::End);
+    runtime.assert_invalid::<Token>(r#"{ "@type": "Span", "@value": { "start": "1" } }"#);
+    runtime.assert_invalid::<Token>(r#"{ "@type": "Pair", "@value": [1] }"#);
+    let handlers = r#"{ text: (v) => v["@value"], pair: () => "", span: () => "", end: () => "" }"#;
+    assert_eq!(
+        runtime.eval(&format!(r#"matchToken(Token.text("a"), {handlers})"#)),
+        Ok(r#""a""#.to_string())
+    );
+
+    Ok(())
+}
+
+#[test]
+fn test_zod_quoted_tags() -> Result<()> {
+    let output = init_path(SCOPE, "test_zod_quoted_tags");
+
+    export_types!(
+        types: [ Circle, Node, Token ],
+        destinations: [(
+            output.ts_path(),
+            emitters: [
+                Zod(),
+            ],
+        )]
+    )?;
+
+    let zod = std::fs::read_to_string(output.ts_path())?;
+    assert!(zod.contains(r#""@type": z.literal("#));
+    assert!(zod.contains(r#""@value": z.string()"#));
+
+    Ok(())
+}
@@ -0,0 +1,128 @@
+mod common;
+
+use anyhow::Result;
+use common::*;
+
+use serde::{Deserialize, Serialize};
+use type_reflect::*;
+
+#[derive(Reflect, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Account {
+    #[serde(skip_deserializing)]
+    pub id: u32,
+    pub email: String,
+    #[serde(skip_serializing)]
+    pub password: String,
+    #[serde(default)]
+    pub display_name: String,
+    #[serde(rename(serialize = "createdAt", deserialize = "created"))]
+    pub created: String,
+    #[serde(alias = "phone_number")]
+    pub phone: Option<String>,
+}
+
+#[derive(Reflect, Serialize, Deserialize)]
+pub struct Unchanged {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub value: Option<u32>,
+}
+
+pub const SCOPE: &'static str = "test_io_types";
+
+#[test]
+fn test_field_directions() {
+    let TypeFieldsDefinition::Named(fields) = Account::fields() else {
+        panic!("expected named fields");
+    };
+    let phone = fields.iter().find(|field| field.name == "phone").unwrap();
+    assert_eq!(phone.directions.aliases, vec!["phone_number".to_string()]);
+
+    let input = Account::fields().in_direction(Direction::Deserialize);
+    let TypeFieldsDefinition::Named(input) = input else {
+        panic!("expected named fields");
+    };
+    let names: Vec<String> = input
+        .iter()
+        .map(|field| field.serialized_name(Account::inflection()))
+        .collect();
+    assert_eq!(names, vec!["email", "password", "displayName", "created", "phone"]);
+
+    assert!(!Unchanged::fields().is_directional());
+}
+
+#[test]
+fn test_typescript_io_types() -> Result<()> {
+    let output = init_path(SCOPE, "test_typescript_io_types");
+
+    export_types!(
+        types: [ Account, Unchanged ],
+        destinations: [(
+            output.ts_path(),
+            emitters: [
+                TypeScript(io_types: true),
+                TSFormat(
+                    tab_size: 2,
+                    line_width: 80,
+                ),
+            ],
+        )]
+    )?;
+
+    let ts = std::fs::read_to_string(output.ts_path())?;
+    assert!(!ts.contains("UnchangedInput"));
+
+    output.write_jest(
+        "AccountInput, AccountOutput",
+        r#"
+
+describe('Input and Output Types', ()=>{
+
+  it("accepts a request without the output-only fields", ()=>{
+    const request: AccountInput = {
+        email: 'ada@example.com',
+        password: 'hunter2',
+        created: '2024-01-01',
+    };
+    expect(request.displayName).toBeUndefined();
+  });
+
+  it("accepts a response without the input-only fields", ()=>{
+    const response: AccountOutput = {
+        id: 1,
+        email: 'ada@example.com',
+        displayName: 'Ada',
+        createdAt: '2024-01-01',
+    };
+    expect(response.id).toEqual(1);
+  });
+
+})
+    "#,
+    )?;
+
+    output.run_ts()
+}
+
+#[test]
+fn test_zod_io_types() -> Result<()> {
+    let output = init_path(SCOPE, "test_zod_io_types");
+
+    export_types!(
+        types: [ Account ],
+        destinations: [(
+            output.ts_path(),
+            emitters: [
+                Zod(io_types: true),
+            ],
+        )]
+    )?;
+
+    let zod = std::fs::read_to_string(output.ts_path())?;
+    assert!(zod.contains("export const AccountInputSchema = z.object({"));
+    assert!(zod.contains("displayName : z.string().optional()"));
+    assert!(zod.contains("export const AccountOutputSchema = z.object({"));
+    assert!(zod.contains("createdAt : z.string()"));
+
+    Ok(())
+}
@@ -0,0 +1,139 @@
+mod common;
+
+use anyhow::Result;
+use common::*;
+
+use serde::{Deserialize, Serialize};
+use type_reflect::*;
+
+/// A registered user
+#[derive(Reflect, Serialize, Deserialize)]
+pub struct User {
+    /// The name shown to other users
+    pub name: String,
+    pub age: Option<u32>,
+    #[reflect(option_style = "nullable")]
+    pub nickname: Option<String>,
+    pub tags: Vec<String>,
+    pub status: Status,
+}
+
+#[derive(Reflect, Serialize, Deserialize)]
+pub struct Point(f32, f32);
+
+#[derive(Reflect, Serialize, Deserialize)]
+pub struct Size {
+    pub width: u32,
+    pub height: u32,
+}
+
+#[derive(Reflect, Serialize, Deserialize)]
+pub enum Status {
+    Active,
+    Suspended,
+}
+
+#[derive(Reflect, Serialize, Deserialize)]
+#[serde(tag = "_case", content = "data")]
+pub enum Shape {
+    Circle { radius: f32 },
+    Polygon(Vec<Point>),
+    Empty,
+}
+
+#[derive(Reflect, Serialize, Deserialize)]
+#[serde(tag = "kind")]
+pub enum Event {
+    Click { x: u32, y: u32 },
+    Resize(Size),
+}
+
+#[derive(Reflect, Serialize, Deserialize)]
+pub enum Command {
+    Stop,
+    Move { x: u32 },
+}
+
+pub struct Tags;
+
+impl Emittable for Tags {
+    fn emit_with<E: TypeEmitter>(emitter: &mut E) -> String {
+        emitter.emit_alias::<Self>()
+    }
+}
+
+impl AliasType for Tags {
+    fn name() -> &'static str {
+        "Tags"
+    }
+    fn source_type() -> Type {
+        Type::Array(Box::new(Type::String))
+    }
+    fn rust() -> String {
+        "pub type Tags = Vec<String>;".to_string()
+    }
+}
+
+pub const SCOPE: &'static str = "test_jsdoc";
+
+#[test]
+fn test_jsdoc() -> Result<()> {
+    let output = init_path(SCOPE, "test_jsdoc");
+
+    export_types!(
+        types: [ User, Point, Size, Status, Shape, Event, Command, Tags ],
+        destinations: [(
+            output.ts_path().with_extension("js"),
+            emitters: [
+                JSDoc(),
+            ],
+        )]
+    )?;
+
+    let contents = std::fs::read_to_string(output.ts_path().with_extension("js"))?;
+    println!("{contents}");
+
+    assert!(contents.starts_with("// @ts-check"));
+    assert!(contents.contains(
+        r#"/**
+ * A registered user
+ * @typedef {Object} User
+ * @property {string} name The name shown to other users
+ * @property {number} [age]
+ * @property {string | null} nickname
+ * @property {Array<string>} tags
+ * @property {Status} status
+ */"#
+    ));
+    assert!(contents.contains(" * @typedef {[number, number]} Point"));
+    assert!(contents.contains(r#" * @typedef {"Active" | "Suspended"} Status"#));
+
+    assert!(contents.contains(
+        r#" * @typedef {Object} ShapeCaseCircle
+ * @property {"Circle"} _case
+ * @property {{ radius: number }} data"#
+    ));
+    assert!(contents.contains(r#" * @property {Array<Point>} data"#));
+    assert!(contents.contains(
+        r#" * @typedef {Object} ShapeCaseEmpty
+ * @property {"Empty"} _case
+ */"#
+    ));
+    assert!(
+        contents
+            .contains(" * @typedef {ShapeCaseCircle | ShapeCasePolygon | ShapeCaseEmpty} Shape")
+    );
+
+    assert!(contents.contains(
+        r#" * @typedef {Object} EventCaseClick
+ * @property {"Click"} kind
+ * @property {number} x
+ * @property {number} y"#
+    ));
+    assert!(contents.contains(r#" * @typedef {{ "kind": "Resize" } & Size} EventCaseResize"#));
+
+    assert!(contents.contains(r#" * @typedef {"Stop" | { "Move": { x: number } }} Command"#));
+    assert!(contents.contains(" * @typedef {Array<string>} Tags"));
+
+    Ok(())
+}
@@ -0,0 +1,133 @@
+mod common;
+
+use anyhow::Result;
+use common::*;
+
+use serde::{Deserialize, Serialize};
+use std::ffi::OsStr;
+use type_reflect::*;
+
+#[derive(Reflect, Serialize, Deserialize)]
+#[reflect(meta(table = "products"))]
+pub struct Product {
+    #[reflect(meta(min_len = 1, max_len = 100, required))]
+    pub name: String,
+    #[reflect(meta(min = -1.5, label = "Price"))]
+    pub price: f64,
+}
+
+#[derive(Reflect, Serialize, Deserialize)]
+#[reflect(meta(version = 2))]
+pub enum Availability {
+    #[reflect(meta(label = "In stock"))]
+    InStock,
+    SoldOut,
+}
+
+/// A custom emitter which lists the metadata of each type
+#[derive(Default)]
+pub struct MetaList {}
+
+fn format_meta(path: &str, meta: &Metadata) -> String {
+    meta.iter()
+        .map(|(key, value)| format!("{path}.{key} = {value:?}\n"))
+        .collect()
+}
+
+impl TypeEmitter for MetaList {
+    fn prefix(&mut self) -> String {
+        String::new()
+    }
+
+    fn emit_struct<T>(&mut self) -> String
+    where
+        T: StructType,
+    {
+        let mut result = format_meta(T::name(), &T::meta());
+        if let TypeFieldsDefinition::Named(fields) = T::fields() {
+            for field in fields {
+                result.push_str(&format_meta(
+                    &format!("{}.{}", T::name(), field.name),
+                    &field.meta,
+                ));
+            }
+        }
+        result
+    }
+
+    fn emit_enum<T>(&mut self) -> String
+    where
+        T: EnumReflectionType,
+    {
+        let mut result = format_meta(T::name(), &T::meta());
+        for case in T::cases() {
+            result.push_str(&format_meta(
+                &format!("{}.{}", T::name(), case.name),
+                &case.meta,
+            ));
+        }
+        result
+    }
+
+    fn emit_alias<T>(&mut self) -> String
+    where
+        T: AliasType,
+    {
+        format_meta(T::name(), &T::meta())
+    }
+
+    fn finalize<P>(&mut self, _path: P) -> Result<(), std::io::Error>
+    where
+        P: AsRef<OsStr>,
+    {
+        Ok(())
+    }
+}
+
+pub const SCOPE: &'static str = "test_metadata";
+
+#[test]
+fn test_struct_metadata() {
+    let name = match Product::fields() {
+        TypeFieldsDefinition::Named(fields) => fields.into_iter().next().unwrap(),
+        _ => panic!("expected named fields"),
+    };
+
+    assert_eq!(
+        Product::meta().get("table").and_then(|v| v.as_str()),
+        Some("products")
+    );
+    assert_eq!(name.meta.get("min_len").and_then(|v| v.as_i64()), Some(1));
+    assert_eq!(name.meta.get("required").and_then(|v| v.as_bool()), Some(true));
+}
+
+#[test]
+fn test_custom_emitter() -> Result<()> {
+    let output = init_path(SCOPE, "test_custom_emitter");
+
+    export_types!(
+        types: [ Product, Availability ],
+        destinations: [(
+            output.ts_path(),
+            emitters: [
+                MetaList(),
+            ],
+        )]
+    )?;
+
+    let listing = std::fs::read_to_string(output.ts_path())?;
+    assert_eq!(
+        listing,
+        r#"Product.table = String("products")
+Product.name.max_len = Int(100)
+Product.name.min_len = Int(1)
+Product.name.required = Bool(true)
+Product.price.label = String("Price")
+Product.price.min = Float(-1.5)
+Availability.version = Int(2)
+Availability.InStock.label = String("In stock")
+"#
+    );
+
+    Ok(())
+}
@@ -0,0 +1,150 @@
+mod common;
+
+use anyhow::Result;
+use common::*;
+
+use serde::{Deserialize, Serialize};
+use serde_repr::{Deserialize_repr, Serialize_repr};
+use type_reflect::*;
+
+#[derive(Reflect, Serialize_repr, Deserialize_repr)]
+#[repr(u8)]
+#[reflect(numeric)]
+pub enum Priority {
+    Low = 1,
+    Normal = 5,
+    Urgent = 10,
+}
+
+#[derive(Reflect, Serialize_repr, Deserialize_repr)]
+#[repr(i32)]
+#[reflect(numeric)]
+pub enum Offset {
+    Behind = -1,
+    Level,
+    Ahead,
+}
+
+#[derive(Reflect, Serialize, Deserialize)]
+#[repr(u8)]
+pub enum Named {
+    First,
+    Second,
+}
+
+#[derive(Reflect, Serialize_repr, Deserialize_repr)]
+#[repr(u64)]
+#[reflect(numeric)]
+pub enum Capacity {
+    Empty = 0,
+    Huge = 1 << 40,
+}
+
+#[derive(Reflect, Serialize, Deserialize)]
+pub struct Task {
+    pub title: String,
+    pub priority: Priority,
+    pub offset: Option<Offset>,
+}
+
+pub const SCOPE: &'static str = "test_numeric_enum";
+
+#[test]
+fn test_discriminants() {
+    let discriminants: Vec<Option<i64>> = Offset::cases()
+        .into_iter()
+        .map(|case| case.discriminant)
+        .collect();
+    assert_eq!(discriminants, vec![Some(-1), Some(0), Some(1)]);
+    assert!(matches!(Named::enum_type(), EnumType::Simple));
+
+    let discriminants: Vec<Option<i64>> = Capacity::cases()
+        .into_iter()
+        .map(|case| case.discriminant)
+        .collect();
+    assert_eq!(discriminants, vec![Some(0), Some(1 << 40)]);
+}
+
+#[test]
+fn test_validation() -> Result<()> {
+    let output = init_path(SCOPE, "test_validation");
+
+    export_types!(
+        types: [ Priority, Offset, Named, Task ],
+        destinations: [(
+            output.ts_path(),
+            emitters: [
+                TypeScript(),
+                TSValidation(),
+                TSFormat(
+                    tab_size: 2,
+                    line_width: 80,
+                ),
+            ],
+        )]
+    )?;
+
+    output.write_jest(
+        "Priority, Offset, Named, Task",
+        r#"
+
+describe('Numeric Enum Validation', ()=>{
+
+  it("emits the discriminants as the enum values", ()=>{
+    expect(Priority.Normal).toEqual(5);
+    expect(Offset.Level).toEqual(0);
+  });
+
+  it("validates a discriminant", ()=>{
+    expect(Priority.validate(10)).toEqual(Priority.Urgent);
+  });
+
+  it("throws an error validating an unknown discriminant", ()=>{
+    expect(() => {
+        Priority.validate(2)
+    }).toThrow();
+  });
+
+  it("throws an error validating the name of a case", ()=>{
+    expect(() => {
+        Priority.validate('Urgent')
+    }).toThrow();
+  });
+
+  it("validates a string enum with a repr which isn't numeric", ()=>{
+    expect(Named.validate('Second')).toEqual(Named.Second);
+  });
+
+  it("validates a struct with numeric enum members", ()=>{
+    expect(() => {
+        Task.validate({ title: 'Ship it', priority: 1, offset: -1 })
+    }).not.toThrow();
+  });
+
+})
+    "#,
+    )?;
+
+    output.run_ts()
+}
+
+#[test]
+fn test_zod_schema() -> Result<()> {
+    let output = init_path(SCOPE, "test_zod_schema");
+
+    export_types!(
+        types: [ Priority ],
+        destinations: [(
+            output.ts_path(),
+            emitters: [
+                Zod(),
+            ],
+        )]
+    )?;
+
+    let zod = std::fs::read_to_string(output.ts_path())?;
+    assert!(zod.contains("Urgent = 10,"));
+    assert!(zod.contains("export const PrioritySchema = z.nativeEnum(Priority)"));
+
+    Ok(())
+}
@@ -0,0 +1,179 @@
+mod common;
+
+use anyhow::Result;
+use common::*;
+
+use serde::{Deserialize, Serialize};
+use type_reflect::*;
+
+#[derive(Reflect, Serialize, Deserialize)]
+pub struct Settings {
+    pub theme: Option<String>,
+    #[reflect(option_style = "optional")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub locale: Option<String>,
+    #[reflect(option_style = "nullish")]
+    #[serde(default)]
+    pub timezone: Option<String>,
+    pub history: Vec<Option<u32>>,
+}
+
+pub const SCOPE: &'static str = "test_option_style";
+
+#[test]
+fn test_option_style_attribute() {
+    let TypeFieldsDefinition::Named(fields) = Settings::fields() else {
+        panic!("expected named fields");
+    };
+    let styles: Vec<Option<OptionStyle>> = fields.iter().map(|field| field.option_style).collect();
+    assert_eq!(
+        styles,
+        vec![
+            None,
+            Some(OptionStyle::Optional),
+            Some(OptionStyle::Nullish),
+            None
+        ]
+    );
+}
+
+#[test]
+fn test_nullable_validation() -> Result<()> {
+    let output = init_path(SCOPE, "test_nullable_validation");
+
+    export_types!(
+        types: [ Settings ],
+        destinations: [(
+            output.ts_path(),
+            emitters: [
+                TypeScript(option_style: OptionStyle::Nullable),
+                TSValidation(option_style: OptionStyle::Nullable),
+                TSFormat(
+                    tab_size: 2,
+                    line_width: 80,
+                ),
+            ],
+        )]
+    )?;
+
+    let ts = std::fs::read_to_string(output.ts_path())?;
+    assert!(ts.contains("theme: string | null;"));
+    assert!(ts.contains("locale?: string;"));
+    assert!(ts.contains("timezone?: string | null;"));
+    assert!(ts.contains("history: Array<number | null>;"));
+
+    output.write_jest(
+        "Settings",
+        r#"
+
+describe('Option Styles', ()=>{
+
+  it("validates null and omitted fields according to their style", ()=>{
+    const settings: Settings = { theme: null, timezone: null, history: [1, null] };
+    expect(() => {
+        Settings.validate(settings)
+    }).not.toThrow();
+  });
+
+  it("throws an error validating a missing nullable field", ()=>{
+    expect(() => {
+        Settings.validate({ history: [] })
+    }).toThrow();
+  });
+
+})
+    "#,
+    )?;
+
+    output.run_ts()
+}
+
+#[test]
+fn test_zod_option_style() -> Result<()> {
+    let output = init_path(SCOPE, "test_zod_option_style");
+
+    export_types!(
+        types: [ Settings ],
+        destinations: [(
+            output.ts_path(),
+            emitters: [
+                Zod(option_style: OptionStyle::Nullable),
+            ],
+        )]
+    )?;
+
+    let zod = std::fs::read_to_string(output.ts_path())?;
+    assert!(zod.contains("theme : z.string().nullable()"));
+    assert!(zod.contains("locale : z.string().optional()"));
+    assert!(zod.contains("timezone : z.string().nullish()"));
+    assert!(zod.contains("history : z.array(z.number().nullable())"));
+
+    Ok(())
+}
+
+#[test]
+fn test_nested_option() -> Result<()> {
+    let output = init_path(SCOPE, "test_nested_option");
+    let zod_output = init_path(SCOPE, "test_zod_nested_option");
+    let jsdoc_output = init_path(SCOPE, "test_jsdoc_nested_option");
+
+    export_types!(
+        types: [ Settings ],
+        destinations: [
+            (
+                output.ts_path(),
+                emitters: [ TypeScript(), TSValidation() ],
+            ),
+            (
+                zod_output.ts_path(),
+                emitters: [ Zod() ],
+            ),
+            (
+                jsdoc_output.ts_path(),
+                emitters: [ JSDoc() ],
+            ),
+        ]
+    )?;
+
+    // An item can't be omitted, so it's `null` whatever the style
+    let ts = std::fs::read_to_string(output.ts_path())?;
+    assert!(ts.contains("theme?: string;"));
+    assert!(ts.contains("history: Array<number | null>;"));
+    let zod = std::fs::read_to_string(zod_output.ts_path())?;
+    assert!(zod.contains("theme : z.string().optional()"));
+    assert!(zod.contains("history : z.array(z.number().nullable())"));
+    let jsdoc = std::fs::read_to_string(jsdoc_output.ts_path())?;
+    assert!(jsdoc.contains("{Array<number | null>} history"));
+
+    Ok(())
+}
+
+#[cfg(feature = "testing")]
+#[test]
+fn test_nested_option_validation() -> Result<()> {
+    use type_reflect::testing::Runtime;
+
+    let output = init_path(SCOPE, "test_nested_option_validation");
+
+    export_types!(
+        types: [ Settings ],
+        destinations: [(
+            output.ts_path(),
+            emitters: [
+                TypeScript(),
+                TSValidation(),
+            ],
+        )]
+    )?;
+
+    let mut runtime = Runtime::load(output.ts_path())?;
+    runtime.assert_valid(&Settings {
+        theme: None,
+        locale: None,
+        timezone: None,
+        history: vec![Some(1), None],
+    });
+    runtime.assert_invalid::<Settings>(r#"{ "history": [1, "2"] }"#);
+
+    Ok(())
+}
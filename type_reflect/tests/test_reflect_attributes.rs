@@ -0,0 +1,122 @@
+mod common;
+
+use anyhow::Result;
+use common::*;
+
+use serde::{Deserialize, Serialize};
+use type_reflect::*;
+
+#[derive(Reflect, Serialize, Deserialize)]
+pub struct Address {
+    pub street: String,
+    pub city: String,
+}
+
+#[derive(Reflect, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+#[reflect(export_name = "Profile")]
+pub struct UserProfile {
+    #[serde(rename = "displayName")]
+    pub name: String,
+    #[reflect(rename = "years")]
+    #[serde(rename = "age")]
+    pub age_in_years: u32,
+    #[reflect(skip)]
+    pub password_hash: String,
+    #[serde(skip)]
+    pub session_count: u32,
+    #[reflect(optional)]
+    #[serde(default)]
+    pub nickname: String,
+    #[reflect(inline)]
+    pub address: Address,
+}
+
+#[derive(Reflect, Serialize, Deserialize)]
+#[serde(tag = "_case", content = "data")]
+pub enum Contact {
+    #[reflect(rename = "user")]
+    Profile { profile: UserProfile },
+    #[reflect(rename_all = "camelCase")]
+    Email { email_address: String },
+    #[reflect(skip)]
+    #[serde(skip)]
+    Internal(u32),
+}
+
+#[derive(Reflect, Serialize, Deserialize)]
+#[reflect(skip_emitters(TSValidation))]
+pub struct Unvalidated {
+    pub value: String,
+}
+
+pub const SCOPE: &'static str = "test_reflect_attributes";
+
+#[test]
+fn test_validation() -> Result<()> {
+    let output = init_path(SCOPE, "test_validation");
+
+    export_types!(
+        types: [ Address, UserProfile, Contact, Unvalidated ],
+        destinations: [(
+            output.ts_path(),
+            emitters: [
+                TypeScript(),
+                TSValidation(),
+                TSFormat(
+                    tab_size: 2,
+                    line_width: 80,
+                ),
+            ],
+        )]
+    )?;
+
+    let ts = std::fs::read_to_string(output.ts_path())?;
+    assert!(ts.contains("export type Profile"));
+    assert!(!ts.contains("passwordHash"));
+    assert!(!ts.contains("sessionCount"));
+    assert!(!ts.contains("Unvalidated.validate"));
+    assert!(!ts.contains("Internal"));
+
+    output.write_jest(
+        "Profile, Contact",
+        r#"
+
+const profile = {
+    displayName: 'Ada',
+    years: 36,
+    address: { street: '1 Main St', city: 'London' },
+};
+
+describe('Reflect Attribute Validation', ()=>{
+
+  it("validates a profile using the renamed fields", ()=>{
+    expect(() => {
+        Profile.validate(profile)
+    }).not.toThrow();
+  });
+
+  it("throws an error validating a profile with an invalid inlined address", ()=>{
+    expect(() => {
+        Profile.validate({...profile, address: { street: '1 Main St' }})
+    }).toThrow();
+  });
+
+  it("validates a contact using the renamed variant", ()=>{
+    expect(() => {
+        Contact.validate({_case: 'user', data: { profile }})
+    }).not.toThrow();
+  });
+
+  it("validates a contact using the variant's rename_all", ()=>{
+    expect(() => {
+        Contact.validate({_case: 'Email', data: { emailAddress: 'ada@example.com' }})
+    }).not.toThrow();
+  });
+
+})
+    "#,
+    )?;
+
+    output.run_ts()
+}
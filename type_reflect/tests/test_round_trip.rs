@@ -0,0 +1,134 @@
+#![cfg(feature = "testing")]
+
+mod common;
+
+use anyhow::Result;
+use common::*;
+
+use serde::{Deserialize, Serialize};
+use type_reflect::testing::{Arbitrary, Runtime};
+use type_reflect::*;
+
+#[derive(Reflect, Serialize, Deserialize)]
+pub struct Address {
+    pub street: String,
+    pub zip: u32,
+}
+
+#[derive(Reflect, Serialize, Deserialize)]
+pub struct User {
+    pub name: String,
+    pub age: Option<u32>,
+    pub score: f64,
+    pub tags: Vec<String>,
+    pub address: Address,
+    pub status: Status,
+}
+
+#[derive(Reflect, Serialize, Deserialize, PartialEq)]
+pub enum Status {
+    Active,
+    Suspended,
+}
+
+#[derive(Reflect, Serialize, Deserialize)]
+#[serde(tag = "_case", content = "data")]
+pub enum Shape {
+    Circle { radius: f32 },
+    Square(f32),
+    Empty,
+}
+
+#[derive(Reflect, Serialize, Deserialize)]
+#[serde(tag = "kind")]
+pub enum Event {
+    Click { x: u32, y: u32 },
+    Move(Address),
+}
+
+pub const SCOPE: &'static str = "test_round_trip";
+
+fn arbitrary(seed: u64) -> Arbitrary {
+    Arbitrary::new(seed)
+        .with::<Address>()
+        .with::<User>()
+        .with::<Status>()
+        .with::<Shape>()
+        .with::<Event>()
+}
+
+#[test]
+fn test_generated_values_deserialize() -> Result<()> {
+    let mut arbitrary = arbitrary(1);
+    arbitrary.mutation_rate = 0.0;
+
+    for _ in 0..200 {
+        serde_json::from_value::<User>(arbitrary.generate("User"))?;
+        serde_json::from_value::<Shape>(arbitrary.generate("Shape"))?;
+        serde_json::from_value::<Event>(arbitrary.generate("Event"))?;
+    }
+
+    Ok(())
+}
+
+#[test]
+fn test_mutated_values() -> Result<()> {
+    let mut arbitrary = arbitrary(2);
+
+    let rejected = (0..200)
+        .filter(|_| serde_json::from_value::<User>(arbitrary.generate("User")).is_err())
+        .count();
+    assert!(rejected > 0);
+    assert!(rejected < 200);
+
+    Ok(())
+}
+
+#[test]
+fn test_generation_is_seeded() -> Result<()> {
+    let (mut first, mut second) = (arbitrary(3), arbitrary(3));
+    for _ in 0..20 {
+        assert_eq!(first.generate("User"), second.generate("User"));
+    }
+    Ok(())
+}
+
+#[test]
+fn test_round_trip() -> Result<()> {
+    let output = init_path(SCOPE, "test_round_trip");
+
+    export_types!(
+        types: [ Address, User, Status, Shape, Event ],
+        destinations: [(
+            output.ts_path(),
+            emitters: [
+                TypeScript(),
+                TSValidation(),
+            ],
+        )]
+    )?;
+
+    let mut runtime = Runtime::load(output.ts_path())?;
+    let mut arbitrary = arbitrary(4);
+
+    let users = runtime.assert_round_trip::<User>(&mut arbitrary, 200);
+    assert!(users.rejected > 0);
+    for status in [Status::Active, Status::Suspended] {
+        assert!(users.accepted.iter().any(|user| user.status == status));
+    }
+    assert!(users.accepted.iter().any(|user| user.age.is_some()));
+    assert!(users.accepted.iter().any(|user| user.age.is_none()));
+
+    let shapes = runtime.assert_round_trip::<Shape>(&mut arbitrary, 200);
+    assert!(shapes.rejected > 0);
+    assert!(shapes.accepted.iter().any(|shape| matches!(shape, Shape::Circle { .. })));
+    assert!(shapes.accepted.iter().any(|shape| matches!(shape, Shape::Square(_))));
+    assert!(shapes.accepted.iter().any(|shape| matches!(shape, Shape::Empty)));
+
+    let events = runtime.assert_round_trip::<Event>(&mut arbitrary, 200);
+    assert!(events.rejected > 0);
+    assert!(events.accepted.iter().any(|event| matches!(event, Event::Click { .. })));
+    assert!(events.accepted.iter().any(|event| matches!(event, Event::Move(_))));
+
+    Ok(())
+}
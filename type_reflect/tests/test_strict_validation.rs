@@ -0,0 +1,201 @@
+mod common;
+
+use anyhow::Result;
+use common::*;
+
+use serde::{Deserialize, Serialize};
+use type_reflect::*;
+
+#[derive(Reflect, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct Login {
+    pub user: String,
+    #[serde(alias = "pass")]
+    pub password: String,
+    pub remember: Option<bool>,
+    pub attempts: Option<u32>,
+    pub note: Option<String>,
+}
+
+#[derive(Reflect, Serialize, Deserialize)]
+#[serde(tag = "type", deny_unknown_fields)]
+pub enum Command {
+    Move { x: i32, y: i32 },
+    Stop,
+}
+
+#[derive(Reflect, Serialize, Deserialize)]
+pub struct Lenient {
+    pub value: String,
+}
+
+#[derive(Reflect, Serialize, Deserialize)]
+pub struct Position {
+    pub x: i32,
+    pub y: i32,
+}
+
+#[derive(Reflect, Serialize, Deserialize)]
+#[serde(tag = "type")]
+pub enum Placement {
+    At(Position),
+    Nowhere,
+}
+
+pub const SCOPE: &'static str = "test_strict_validation";
+
+#[test]
+fn test_deny_unknown_fields() {
+    assert!(Login::deny_unknown_fields());
+    assert!(Command::deny_unknown_fields());
+    assert!(!Lenient::deny_unknown_fields());
+    assert!(serde_json::from_str::<Login>(r#"{"user":"ada","password":"x","admin":true}"#).is_err());
+}
+
+#[test]
+fn test_validation() -> Result<()> {
+    let output = init_path(SCOPE, "test_validation");
+
+    export_types!(
+        types: [ Login, Command, Lenient ],
+        destinations: [(
+            output.ts_path(),
+            emitters: [
+                TypeScript(),
+                TSValidation(),
+                TSFormat(
+                    tab_size: 2,
+                    line_width: 80,
+                ),
+            ],
+        )]
+    )?;
+
+    output.write_jest(
+        "Login, Command, Lenient",
+        r#"
+
+describe('Strict Validation', ()=>{
+
+  it("validates falsy optional values", ()=>{
+    expect(() => {
+        Login.validate({ user: 'ada', password: '', remember: false, attempts: 0, note: '' })
+    }).not.toThrow();
+  });
+
+  it("throws an error validating a falsy optional value of the wrong type", ()=>{
+    expect(() => {
+        Login.validate({ user: 'ada', password: 'x', note: 0 })
+    }).toThrow();
+    expect(() => {
+        Login.validate({ user: 'ada', password: 'x', attempts: '' })
+    }).toThrow();
+  });
+
+  it("validates null optional values", ()=>{
+    expect(() => {
+        Login.validate({ user: 'ada', password: 'x', note: null })
+    }).not.toThrow();
+  });
+
+  it("throws an error validating an unknown field", ()=>{
+    expect(() => {
+        Login.validate({ user: 'ada', password: 'x', admin: true })
+    }).toThrow();
+  });
+
+  it("validates a field under its alias", ()=>{
+    expect(() => {
+        Login.validate({ user: 'ada', pass: 'x' })
+    }).not.toThrow();
+  });
+
+  it("throws an error validating an unknown field of a variant", ()=>{
+    expect(() => {
+        Command.validate({ type: 'Move', x: 1, y: 2 })
+    }).not.toThrow();
+    expect(() => {
+        Command.validate({ type: 'Move', x: 1, y: 2, z: 3 })
+    }).toThrow();
+    expect(() => {
+        Command.validate({ type: 'Stop', speed: 0 })
+    }).toThrow();
+  });
+
+  it("ignores unknown fields of a lenient type", ()=>{
+    expect(() => {
+        Lenient.validate({ value: 'x', extra: 1 })
+    }).not.toThrow();
+  });
+
+})
+    "#,
+    )?;
+
+    output.run_ts()
+}
+
+#[test]
+fn test_strict_mode() -> Result<()> {
+    let output = init_path(SCOPE, "test_strict_mode");
+
+    export_types!(
+        types: [ Lenient ],
+        destinations: [(
+            output.ts_path(),
+            emitters: [
+                TypeScript(),
+                TSValidation(strict: true),
+                TSFormat(
+                    tab_size: 2,
+                    line_width: 80,
+                ),
+            ],
+        )]
+    )?;
+
+    output.write_jest(
+        "Lenient",
+        r#"
+
+describe('Strict Mode', ()=>{
+
+  it("throws an error validating an unknown field", ()=>{
+    expect(() => {
+        Lenient.validate({ value: 'x', extra: 1 })
+    }).toThrow();
+  });
+
+})
+    "#,
+    )?;
+
+    output.run_ts()
+}
+
+#[cfg(feature = "testing")]
+#[test]
+fn test_strict_internally_tagged_newtype() -> Result<()> {
+    use type_reflect::testing::Runtime;
+
+    let output = init_path(SCOPE, "test_strict_internally_tagged_newtype");
+
+    export_types!(
+        types: [ Position, Placement ],
+        destinations: [(
+            output.ts_path(),
+            emitters: [
+                TypeScript(),
+                TSValidation(strict: true),
+            ],
+        )]
+    )?;
+
+    let mut runtime = Runtime::load(output.ts_path())?;
+    runtime.assert_valid(&Placement::At(Position { x: 1, y: 2 }));
+    runtime.assert_valid(&Placement::Nowhere);
+    runtime.assert_invalid::<Placement>(r#"{ "type": "At", "x": 1, "y": 2, "z": 3 }"#);
+    runtime.assert_invalid::<Placement>(r#"{ "type": "At", "x": 1 }"#);
+
+    Ok(())
+}
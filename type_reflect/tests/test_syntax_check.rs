@@ -0,0 +1,159 @@
+mod common;
+
+use std::ffi::OsStr;
+
+use anyhow::Result;
+use common::*;
+
+use serde::{Deserialize, Serialize};
+use type_reflect::*;
+
+#[derive(Reflect, Serialize, Deserialize)]
+pub struct User {
+    pub name: String,
+}
+
+/// An emitter with a bug, which leaves the type it emits unterminated
+#[derive(Default)]
+pub struct Broken {}
+
+impl TypeEmitter for Broken {
+    fn prefix(&mut self) -> String {
+        String::new()
+    }
+
+    fn emit_struct<T>(&mut self) -> String
+    where
+        T: StructType,
+    {
+        format!("export type {} = {{ name: string;\n", T::name())
+    }
+
+    fn emit_enum<T>(&mut self) -> String
+    where
+        T: EnumReflectionType,
+    {
+        String::new()
+    }
+
+    fn emit_alias<T>(&mut self) -> String
+    where
+        T: AliasType,
+    {
+        String::new()
+    }
+
+    fn finalize<P>(&mut self, _path: P) -> Result<(), std::io::Error>
+    where
+        P: AsRef<OsStr>,
+    {
+        Ok(())
+    }
+}
+
+/// An emitter whose output parses, but only after the parser recovers from an error
+#[derive(Default)]
+pub struct Octal {}
+
+impl TypeEmitter for Octal {
+    fn prefix(&mut self) -> String {
+        String::new()
+    }
+
+    fn emit_struct<T>(&mut self) -> String
+    where
+        T: StructType,
+    {
+        format!("export const {}Version = 010;\n", T::name())
+    }
+
+    fn emit_enum<T>(&mut self) -> String
+    where
+        T: EnumReflectionType,
+    {
+        String::new()
+    }
+
+    fn emit_alias<T>(&mut self) -> String
+    where
+        T: AliasType,
+    {
+        String::new()
+    }
+
+    fn finalize<P>(&mut self, _path: P) -> Result<(), std::io::Error>
+    where
+        P: AsRef<OsStr>,
+    {
+        Ok(())
+    }
+}
+
+pub const SCOPE: &'static str = "test_syntax_check";
+
+#[test]
+fn test_valid_fragments() -> Result<()> {
+    let output = init_path(SCOPE, "test_valid_fragments");
+
+    export_types!(
+        types: [ User ],
+        destinations: [(
+            output.ts_path(),
+            emitters: [
+                TypeScript(),
+                TSValidation(),
+                Zod(),
+            ],
+        )]
+    )?;
+
+    Ok(())
+}
+
+#[test]
+fn test_invalid_fragment() -> Result<()> {
+    let output = init_path(SCOPE, "test_invalid_fragment");
+
+    let result = export_types!(
+        types: [ User ],
+        destinations: [(
+            output.ts_path(),
+            emitters: [
+                TypeScript(),
+                Broken(),
+            ],
+        )]
+    );
+
+    let err = result.expect_err("the broken fragment should fail the export");
+    assert_eq!(err.kind(), std::io::ErrorKind::InvalidData);
+    let message = err.to_string();
+    println!("{message}");
+    assert!(message.starts_with("Broken emitted invalid syntax for `test_syntax_check::User`"));
+
+    Ok(())
+}
+
+#[test]
+fn test_recovered_fragment() -> Result<()> {
+    let output = init_path(SCOPE, "test_recovered_fragment");
+
+    let result = export_types!(
+        types: [ User ],
+        destinations: [(
+            output.ts_path(),
+            emitters: [
+                TypeScript(),
+                Octal(),
+            ],
+        )]
+    );
+
+    let err = result.expect_err("the recovered parse error should fail the export");
+    assert_eq!(err.kind(), std::io::ErrorKind::InvalidData);
+    let message = err.to_string();
+    assert!(message.starts_with("Octal emitted invalid syntax for `test_syntax_check::User`"));
+    assert!(message.contains("Legacy octal"));
+
+    Ok(())
+}
@@ -0,0 +1,115 @@
+mod common;
+
+use anyhow::Result;
+use common::*;
+
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use type_reflect::*;
+
+#[derive(Reflect, Serialize, Deserialize)]
+pub struct Order {
+    pub id: uuid::Uuid,
+    pub created_at: DateTime<Utc>,
+    pub shipped_on: Option<chrono::NaiveDate>,
+    pub receipt: url::Url,
+    pub total: rust_decimal::Decimal,
+}
+
+/// A local type which shares its name with `rust_decimal::Decimal`
+#[derive(Reflect, Serialize, Deserialize)]
+pub struct Decimal {
+    pub mantissa: i64,
+    pub scale: u32,
+}
+
+#[derive(Reflect, Serialize, Deserialize)]
+pub struct Invoice {
+    pub total: Decimal,
+}
+
+pub const SCOPE: &'static str = "test_third_party_types";
+
+#[test]
+fn test_local_type_is_not_mapped() {
+    let TypeFieldsDefinition::Named(fields) = Invoice::fields() else {
+        panic!("expected named fields");
+    };
+    assert!(matches!(&fields[0].type_, Type::Named(named) if named.name == "Decimal"));
+
+    let TypeFieldsDefinition::Named(fields) = Order::fields() else {
+        panic!("expected named fields");
+    };
+    assert!(matches!(
+        fields[4].type_,
+        Type::FormattedString(StringFormat::Decimal)
+    ));
+}
+
+#[test]
+fn test_validation() -> Result<()> {
+    let output = init_path(SCOPE, "test_validation");
+
+    export_types!(
+        types: [ Order ],
+        destinations: [(
+            output.ts_path(),
+            emitters: [
+                TypeScript(),
+                TSValidation(),
+                TSFormat(
+                    tab_size: 2,
+                    line_width: 80,
+                ),
+            ],
+        )]
+    )?;
+
+    let order = Order {
+        id: uuid::Uuid::nil(),
+        created_at: DateTime::from_timestamp(1_700_000_000, 0).unwrap(),
+        shipped_on: chrono::NaiveDate::from_ymd_opt(2023, 11, 15),
+        receipt: url::Url::parse("https://example.com/receipts/1")?,
+        total: rust_decimal::Decimal::new(1999, 2),
+    };
+    let json = serde_json::to_string(&order)?;
+
+    output.write_jest(
+        "Order",
+        format!(
+            r#"
+
+describe('Third-party Type Validation', ()=>{{
+
+  it("validates a serialized `Order`", ()=>{{
+    expect(() => {{
+        Order.parse(`{json}`)
+    }}).not.toThrow();
+  }});
+
+  it("throws an error validating an `Order` with a malformed uuid", ()=>{{
+    expect(() => {{
+        Order.validate({{...JSON.parse(`{json}`), id: "not-a-uuid"}})
+    }}).toThrow();
+  }});
+
+  it("throws an error validating an `Order` with a malformed date-time", ()=>{{
+    expect(() => {{
+        Order.validate({{...JSON.parse(`{json}`), created_at: "yesterday"}})
+    }}).toThrow();
+  }});
+
+  it("throws an error validating an `Order` with a numeric decimal", ()=>{{
+    expect(() => {{
+        Order.validate({{...JSON.parse(`{json}`), total: 19.99}})
+    }}).toThrow();
+  }});
+
+}})
+    "#
+        )
+        .as_str(),
+    )?;
+
+    output.run_ts()
+}
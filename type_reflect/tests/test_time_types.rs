@@ -0,0 +1,79 @@
+mod common;
+
+use anyhow::Result;
+use common::*;
+
+use serde::{Deserialize, Serialize};
+use time::{macros::datetime, OffsetDateTime};
+use type_reflect::*;
+
+#[derive(Reflect, Serialize, Deserialize)]
+pub struct Session {
+    #[serde(with = "time::serde::rfc3339")]
+    pub started_at: OffsetDateTime,
+    #[serde(with = "time::serde::rfc3339::option")]
+    pub ended_at: Option<OffsetDateTime>,
+}
+
+/// Without a `with` module, `time` uses its own format, which isn't mapped
+#[derive(Reflect, Serialize, Deserialize)]
+pub struct Event {
+    pub at: OffsetDateTime,
+}
+
+pub const SCOPE: &'static str = "test_time_types";
+
+#[test]
+fn test_reflection() {
+    let TypeFieldsDefinition::Named(fields) = Session::fields() else {
+        panic!("expected named fields");
+    };
+    assert!(matches!(
+        fields[0].type_,
+        Type::FormattedString(StringFormat::DateTime)
+    ));
+    let Type::Option(ended_at) = &fields[1].type_ else {
+        panic!("expected an optional field");
+    };
+    assert!(matches!(
+        **ended_at,
+        Type::FormattedString(StringFormat::DateTime)
+    ));
+
+    let TypeFieldsDefinition::Named(fields) = Event::fields() else {
+        panic!("expected named fields");
+    };
+    assert!(matches!(&fields[0].type_, Type::Named(named) if named.name == "OffsetDateTime"));
+}
+
+#[cfg(feature = "testing")]
+#[test]
+fn test_validation() -> Result<()> {
+    use type_reflect::testing::Runtime;
+
+    let output = init_path(SCOPE, "test_validation");
+
+    export_types!(
+        types: [ Session ],
+        destinations: [(
+            output.ts_path(),
+            emitters: [
+                TypeScript(),
+                TSValidation(),
+            ],
+        )]
+    )?;
+
+    let mut runtime = Runtime::load(output.ts_path())?;
+    runtime.assert_valid(&Session {
+        started_at: datetime!(2024-05-01 12:30:00 +02:00),
+        ended_at: Some(datetime!(2024-05-01 13:00:00.5 UTC)),
+    });
+    runtime.assert_valid(&Session {
+        started_at: datetime!(2024-05-01 12:30:00 UTC),
+        ended_at: None,
+    });
+    runtime.assert_invalid::<Session>(r#"{ "started_at": "2024-05-01 12:30:00.0 +02:00:00" }"#);
+
+    Ok(())
+}
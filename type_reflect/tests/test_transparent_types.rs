@@ -0,0 +1,200 @@
+mod common;
+
+use std::borrow::Cow;
+use std::cell::{Cell, RefCell};
+use std::marker::PhantomData;
+use std::sync::{Mutex, RwLock};
+
+use anyhow::Result;
+use common::*;
+
+use serde::{Deserialize, Serialize};
+use type_reflect::*;
+
+#[derive(Reflect, Serialize, Deserialize)]
+pub struct Point {
+    pub x: f32,
+    pub y: f32,
+}
+
+#[derive(Reflect, Serialize, Deserialize)]
+pub struct Shared {
+    pub count: Mutex<u32>,
+    pub names: RwLock<Vec<String>>,
+    pub label: Cow<'static, str>,
+    pub visible: Cell<bool>,
+    pub origin: RefCell<Point>,
+    #[serde(skip)]
+    pub marker: PhantomData<Point>,
+}
+
+#[derive(Reflect, Serialize, Deserialize)]
+pub struct Tagged {
+    pub point: Point,
+    pub marker: PhantomData<Point>,
+}
+
+#[derive(Reflect, Serialize, Deserialize)]
+pub struct Qualified {
+    pub count: std::sync::Mutex<u32>,
+    pub label: std::borrow::Cow<'static, str>,
+    pub marker: std::marker::PhantomData<Point>,
+}
+
+pub const SCOPE: &'static str = "test_transparent_types";
+
+#[test]
+fn test_reflection() -> Result<()> {
+    let TypeFieldsDefinition::Named(fields) = Shared::fields() else {
+        panic!("expected named fields");
+    };
+    let names: Vec<&str> = fields.iter().map(|field| field.name.as_str()).collect();
+    assert_eq!(names, vec!["count", "names", "label", "visible", "origin"]);
+    assert!(fields
+        .iter()
+        .all(|field| matches!(field.type_, Type::Transparent(_))));
+
+    let shared = Shared {
+        count: Mutex::new(2),
+        names: RwLock::new(vec!["a".to_string()]),
+        label: Cow::Borrowed("pin"),
+        visible: Cell::new(true),
+        origin: RefCell::new(Point { x: 1.0, y: 2.0 }),
+        marker: PhantomData,
+    };
+    assert_eq!(
+        serde_json::to_string(&shared)?,
+        r#"{"count":2,"names":["a"],"label":"pin","visible":true,"origin":{"x":1.0,"y":2.0}}"#
+    );
+
+    let TypeFieldsDefinition::Named(fields) = Tagged::fields() else {
+        panic!("expected named fields");
+    };
+    assert_eq!(fields[1].name, "marker");
+    assert!(matches!(fields[1].type_, Type::Null));
+    // serde requires the `null` of an unskipped `PhantomData` field
+    assert!(serde_json::from_str::<Tagged>(r#"{"point":{"x":1.0,"y":2.0}}"#).is_err());
+
+    let TypeFieldsDefinition::Named(fields) = Qualified::fields() else {
+        panic!("expected named fields");
+    };
+    assert!(matches!(fields[0].type_, Type::Transparent(_)));
+    assert!(matches!(fields[1].type_, Type::Transparent(_)));
+    assert!(matches!(fields[2].type_, Type::Null));
+    Ok(())
+}
+
+#[cfg(feature = "testing")]
+#[test]
+fn test_phantom_data() -> Result<()> {
+    use type_reflect::testing::Runtime;
+
+    let output = init_path(SCOPE, "test_phantom_data");
+
+    export_types!(
+        types: [ Point, Tagged ],
+        destinations: [(
+            output.ts_path(),
+            emitters: [
+                TypeScript(),
+                TSValidation(strict: true),
+            ],
+        )]
+    )?;
+
+    let ts = std::fs::read_to_string(output.ts_path())?;
+    assert!(ts.contains("marker: null"));
+
+    let tagged = Tagged {
+        point: Point { x: 1.0, y: 2.0 },
+        marker: PhantomData,
+    };
+    assert_eq!(
+        serde_json::to_string(&tagged)?,
+        r#"{"point":{"x":1.0,"y":2.0},"marker":null}"#
+    );
+
+    let mut runtime = Runtime::load(output.ts_path())?;
+    runtime.assert_valid(&tagged);
+    runtime.assert_invalid::<Tagged>(r#"{ "point": { "x": 1, "y": 2 } }"#);
+    runtime.assert_invalid::<Tagged>(r#"{ "point": { "x": 1, "y": 2 }, "marker": {} }"#);
+
+    Ok(())
+}
+
+#[test]
+fn test_validation() -> Result<()> {
+    let output = init_path(SCOPE, "test_validation");
+
+    export_types!(
+        types: [ Point, Shared ],
+        destinations: [(
+            output.ts_path(),
+            emitters: [
+                TypeScript(),
+                TSValidation(),
+                TSFormat(
+                    tab_size: 2,
+                    line_width: 80,
+                ),
+            ],
+        )]
+    )?;
+
+    let ts = std::fs::read_to_string(output.ts_path())?;
+    assert!(!ts.contains("marker"));
+
+    output.write_jest(
+        "Shared",
+        r#"
+
+const shared = {
+    count: 2,
+    names: ['a'],
+    label: 'pin',
+    visible: true,
+    origin: { x: 1, y: 2 },
+};
+
+describe('Transparent Type Validation', ()=>{
+
+  it("validates the wrapped values", ()=>{
+    expect(() => Shared.validate(shared)).not.toThrow();
+  });
+
+  it("reports a wrapped value of the wrong type", ()=>{
+    const result = Shared.safeValidate({ ...shared, origin: { x: 1 } });
+    expect(result.ok).toBe(false);
+    if (!result.ok) {
+        expect(result.errors[0].path).toEqual('/origin/y');
+    }
+  });
+
+})
+    "#,
+    )?;
+
+    output.run_ts()
+}
+
+#[test]
+fn test_zod() -> Result<()> {
+    let output = init_path(SCOPE, "test_zod");
+
+    export_types!(
+        types: [ Point, Shared ],
+        destinations: [(
+            output.ts_path(),
+            emitters: [
+                Zod(),
+            ],
+        )]
+    )?;
+
+    let zod = std::fs::read_to_string(output.ts_path())?;
+    assert!(zod.contains("count : z.number()"));
+    assert!(zod.contains("names : z.array(z.string())"));
+    assert!(zod.contains("origin : PointSchema"));
+
+    Ok(())
+}
@@ -0,0 +1,54 @@
+mod common;
+
+use anyhow::Result;
+use common::*;
+
+use serde::{Deserialize, Serialize};
+use type_reflect::*;
+
+#[derive(Reflect, Serialize, Deserialize)]
+pub struct Order {
+    pub id: u32,
+    pub items: Vec<String>,
+}
+
+#[derive(Reflect, Serialize, Deserialize)]
+#[serde(tag = "_case", content = "data")]
+pub enum Status {
+    Pending,
+    Shipped { carrier: String },
+}
+
+pub const SCOPE: &'static str = "test_transpile";
+
+#[test]
+fn test_transpile() -> Result<()> {
+    let output = init_path(SCOPE, "test_transpile");
+
+    export_types!(
+        types: [ Order, Status ],
+        destinations: [(
+            output.ts_path(),
+            emitters: [
+                TypeScript(),
+                TSValidation(),
+                TSTranspile(),
+            ],
+        )]
+    )?;
+
+    let js = std::fs::read_to_string(output.ts_path().with_extension("js"))?;
+    assert!(js.contains("export function isOrder(value)"));
+    assert!(!js.contains("export type"));
+
+    let declarations = std::fs::read_to_string(output.ts_path().with_extension("d.ts"))?;
+    assert!(declarations.contains("export type Order = {"));
+    assert!(declarations.contains("export declare function isOrder(value: unknown): value is Order;"));
+    assert!(declarations.contains("export declare namespace Order {"));
+    assert!(declarations.contains("function validate(input: any): Order;"));
+    assert!(declarations.contains("export declare const StatusCaseKey: {"));
+    assert!(declarations.contains("export declare class ValidationFailure extends Error {"));
+    assert!(!declarations.contains("isRecord"));
+
+    Ok(())
+}
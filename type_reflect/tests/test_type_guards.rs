@@ -0,0 +1,82 @@
+mod common;
+
+use anyhow::Result;
+use common::*;
+
+use serde::{Deserialize, Serialize};
+use type_reflect::*;
+
+#[derive(Reflect, Serialize, Deserialize)]
+pub struct Task {
+    pub title: String,
+    pub status: Status,
+}
+
+#[derive(Reflect, Serialize, Deserialize)]
+#[serde(tag = "_case", content = "data")]
+pub enum Status {
+    Todo,
+    InProgress { percent: f64 },
+    Done,
+}
+
+pub const SCOPE: &'static str = "test_type_guards";
+
+#[test]
+fn test_validation() -> Result<()> {
+    let output = init_path(SCOPE, "test_validation");
+
+    export_types!(
+        types: [ Task, Status ],
+        destinations: [(
+            output.ts_path(),
+            emitters: [
+                TypeScript(),
+                TSValidation(),
+                TSFormat(
+                    tab_size: 2,
+                    line_width: 80,
+                ),
+            ],
+        )]
+    )?;
+
+    output.write_jest(
+        "Task, Status, isTask, isStatus",
+        r#"
+
+describe('Type Guards', ()=>{
+
+  it("narrows a valid task", ()=>{
+    const input: unknown = { title: 'Write docs', status: { _case: 'Todo' } };
+    expect(isTask(input)).toBe(true);
+    if (isTask(input)) {
+        expect(input.title).toEqual('Write docs');
+    }
+  });
+
+  it("rejects an invalid task", ()=>{
+    expect(isTask({ title: 'Write docs', status: { _case: 'Paused' } })).toBe(false);
+    expect(isTask(null)).toBe(false);
+  });
+
+  it("guards an enum value", ()=>{
+    expect(isStatus({ _case: 'InProgress', data: { percent: 50 } })).toBe(true);
+    expect(isStatus({ _case: 'InProgress', data: { percent: 'half' } })).toBe(false);
+  });
+
+  it("narrows an enum value by its variant", ()=>{
+    const status: Status = Status.validate({ _case: 'InProgress', data: { percent: 50 } });
+    expect(Status.isTodo(status)).toBe(false);
+    expect(Status.isInProgress(status)).toBe(true);
+    if (Status.isInProgress(status)) {
+        expect(status.data.percent).toEqual(50);
+    }
+  });
+
+})
+    "#,
+    )?;
+
+    output.run_ts()
+}
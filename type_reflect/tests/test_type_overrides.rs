@@ -0,0 +1,138 @@
+mod common;
+
+use anyhow::Result;
+use common::*;
+
+use serde::{Deserialize, Serialize};
+use type_reflect::*;
+
+/// Money is not reflected, so it's mapped with a global override
+#[derive(Serialize, Deserialize)]
+pub struct Money(i64);
+
+#[derive(Reflect, Serialize, Deserialize)]
+pub struct Invoice {
+    #[reflect(
+        type = "Lowercase<string>",
+        validate = "(value: any) => { if (typeof value !== 'string' || value !== value.toLowerCase()) { throw new Error('expected lowercase') } }"
+    )]
+    pub code: String,
+    pub total: Money,
+    pub discount: Option<Money>,
+}
+
+pub const SCOPE: &'static str = "test_type_overrides";
+
+#[test]
+fn test_validation() -> Result<()> {
+    let output = init_path(SCOPE, "test_validation");
+
+    export_types!(
+        types: [ Invoice ],
+        type_overrides: [
+            Money: (
+                type: "number",
+                validate: "(value: any) => { if (!Number.isInteger(value)) { throw new Error('expected integer') } }",
+            ),
+        ],
+        destinations: [(
+            output.ts_path(),
+            emitters: [
+                TypeScript(),
+                TSValidation(),
+                TSFormat(
+                    tab_size: 2,
+                    line_width: 80,
+                ),
+            ],
+        )]
+    )?;
+
+    output.write_jest(
+        "Invoice",
+        r#"
+
+describe('Type Override Validation', ()=>{
+
+  it("validates an object: `{code: 'abc', total: 100}` using the overrides", ()=>{
+    expect(() => {
+        Invoice.validate({code: 'abc', total: 100})
+    }).not.toThrow();
+  });
+
+  it("validates an object: `{code: 'abc', total: 100, discount: 5}` with the optional override", ()=>{
+    expect(() => {
+        Invoice.validate({code: 'abc', total: 100, discount: 5})
+    }).not.toThrow();
+  });
+
+  it("throws an error validating an object: `{code: 'ABC', total: 100}` failing the field override", ()=>{
+    expect(() => {
+        Invoice.validate({code: 'ABC', total: 100})
+    }).toThrow();
+  });
+
+  it("throws an error validating an object: `{code: 'abc', total: 1.5}` failing the global override", ()=>{
+    expect(() => {
+        Invoice.validate({code: 'abc', total: 1.5})
+    }).toThrow();
+  });
+
+})
+    "#,
+    )?;
+
+    output.run_ts()
+}
+
+#[test]
+fn test_overrides_are_scoped_to_the_export() -> Result<()> {
+    let output = init_path(SCOPE, "test_overrides_are_scoped_to_the_export");
+    let next_output = init_path(SCOPE, "test_overrides_are_scoped_to_the_next_export");
+
+    export_types!(
+        types: [ Invoice ],
+        type_overrides: [
+            Money: (type: "number"),
+        ],
+        destinations: [(
+            output.ts_path(),
+            emitters: [ TypeScript() ],
+        )]
+    )?;
+    assert!(type_override("Money").is_none());
+
+    export_types!(
+        types: [ Invoice ],
+        destinations: [(
+            next_output.ts_path(),
+            emitters: [ TypeScript() ],
+        )]
+    )?;
+
+    let ts = std::fs::read_to_string(output.ts_path())?;
+    assert!(ts.contains("total: number;"));
+    let ts = std::fs::read_to_string(next_output.ts_path())?;
+    assert!(ts.contains("total: Money;"));
+
+    Ok(())
+}
+
+#[test]
+fn test_overrides_are_per_thread() -> Result<()> {
+    let _overrides = TypeOverrides::new()
+        .with(
+            "Money",
+            TypeOverride {
+                type_script: Some("number".to_string()),
+                zod: None,
+                validation: None,
+            },
+        )
+        .install();
+    assert!(type_override("Money").is_some());
+    let other_thread = std::thread::spawn(|| type_override("Money").is_some()).join();
+    assert_eq!(other_thread.ok(), Some(false));
+
+    Ok(())
+}
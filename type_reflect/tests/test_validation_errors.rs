@@ -0,0 +1,190 @@
+mod common;
+
+use std::collections::HashMap;
+
+use anyhow::Result;
+use common::*;
+
+use serde::{Deserialize, Serialize};
+use type_reflect::*;
+
+#[derive(Reflect, Serialize, Deserialize)]
+pub struct LineItem {
+    pub name: String,
+    pub price: f64,
+}
+
+#[derive(Reflect, Serialize, Deserialize)]
+pub struct Order {
+    pub id: u32,
+    pub items: Vec<LineItem>,
+    pub tags: HashMap<String, u32>,
+    pub note: Option<String>,
+}
+
+#[derive(Reflect, Serialize, Deserialize)]
+#[serde(tag = "status", content = "data")]
+pub enum Delivery {
+    Pending,
+    Shipped { carrier: String },
+    Delivered { signed_by: Option<String> },
+}
+
+pub const SCOPE: &'static str = "test_validation_errors";
+
+#[test]
+fn test_validation() -> Result<()> {
+    let output = init_path(SCOPE, "test_validation");
+
+    export_types!(
+        types: [ LineItem, Order, Delivery ],
+        destinations: [(
+            output.ts_path(),
+            emitters: [
+                TypeScript(),
+                TSValidation(),
+                TSFormat(
+                    tab_size: 2,
+                    line_width: 80,
+                ),
+            ],
+        )]
+    )?;
+
+    output.write_jest(
+        "Order, Delivery, ValidationFailure",
+        r#"
+
+const order = {
+    id: 1,
+    items: [{ name: 'Tea', price: 3.5 }, { name: 'Cake', price: 4 }],
+    tags: { 'gift/wrap': 1 },
+};
+
+describe('Validation Errors', ()=>{
+
+  it("returns the value of a valid input", ()=>{
+    const result = Order.safeValidate(order);
+    expect(result.ok).toBe(true);
+    if (result.ok) {
+        expect(result.value.items.length).toEqual(2);
+    }
+  });
+
+  it("collects every error with its JSON pointer", ()=>{
+    const result = Order.safeValidate({
+        id: 'one',
+        items: [{ name: 'Tea', price: 3.5 }, { name: 'Cake', price: 'free' }],
+        tags: { 'gift/wrap': 'yes', 'a~b': 2 },
+        note: 7,
+    });
+    expect(result.ok).toBe(false);
+    if (!result.ok) {
+        expect(result.errors.map(error => error.path)).toEqual([
+            '/id',
+            '/items/1/price',
+            '/tags/gift~1wrap',
+            '/note',
+        ]);
+        expect(result.errors[0].message).toEqual('expected: number, found: string');
+    }
+  });
+
+  it("reports an error at the root of the input", ()=>{
+    const result = Order.safeValidate([]);
+    expect(result.ok).toBe(false);
+    if (!result.ok) {
+        expect(result.errors).toEqual([{ path: '', message: 'expected: Record, found: object' }]);
+    }
+  });
+
+  it("throws the errors from validate", ()=>{
+    let error: unknown;
+    try {
+        Order.validate({ ...order, id: null });
+    } catch (e) {
+        error = e;
+    }
+    expect(error).toBeInstanceOf(ValidationFailure);
+    expect((error as ValidationFailure).errors[0].path).toEqual('/id');
+  });
+
+  it("returns undefined from tryValidate", ()=>{
+    expect(Order.tryValidate({ ...order, items: null })).toBeUndefined();
+  });
+
+  it("prefixes the paths of array items with their index", ()=>{
+    const result = Order.safeValidateArray([order, { ...order, id: 'two' }]);
+    expect(result.ok).toBe(false);
+    if (!result.ok) {
+        expect(result.errors.map(error => error.path)).toEqual(['/1/id']);
+    }
+  });
+
+  it("returns undefined from tryParseArray", ()=>{
+    expect(Order.tryParseArray(JSON.stringify([order]))).toHaveLength(1);
+    expect(Order.tryParseArray(JSON.stringify([order, { ...order, id: 'two' }]))).toBeUndefined();
+  });
+
+  it("reports the errors of the variant named by the tag", ()=>{
+    expect(Delivery.validate({ status: 'Shipped', data: { carrier: 'Post' } })).toBeDefined();
+    const result = Delivery.safeValidate({ status: 'Shipped', data: { carrier: 3 } });
+    expect(result.ok).toBe(false);
+    if (!result.ok) {
+        expect(result.errors.map(error => error.path)).toEqual(['/data/carrier']);
+    }
+  });
+
+  it("reports an unknown tag with the allowed values", ()=>{
+    const result = Delivery.safeValidate({ status: 'Lost' });
+    expect(result.ok).toBe(false);
+    if (!result.ok) {
+        expect(result.errors).toEqual([{
+            path: '/status',
+            message: 'unknown tag: "Lost", expected one of: Pending, Shipped, Delivered',
+        }]);
+    }
+  });
+
+})
+    "#,
+    )?;
+
+    output.run_ts()
+}
+
+#[cfg(feature = "testing")]
+#[test]
+fn test_try_parse_array() -> Result<()> {
+    use type_reflect::testing::Runtime;
+
+    let output = init_path(SCOPE, "test_try_parse_array");
+
+    export_types!(
+        types: [ LineItem, Order ],
+        destinations: [(
+            output.ts_path(),
+            emitters: [
+                TypeScript(),
+                TSValidation(),
+            ],
+        )]
+    )?;
+
+    let mut runtime = Runtime::load(output.ts_path())?;
+    let order = r#"{ "id": 1, "items": [], "tags": {} }"#;
+    assert_eq!(
+        runtime.eval(&format!("Order.tryParseArray(JSON.stringify([{order}])).length")),
+        Ok("1".to_string())
+    );
+    assert_eq!(
+        runtime.eval(r#"Order.tryParseArray('[{ "id": "two" }]')"#),
+        Ok("undefined".to_string())
+    );
+    assert_eq!(
+        runtime.eval(r#"Order.tryParseArray('{}')"#),
+        Ok("undefined".to_string())
+    );
+
+    Ok(())
+}
@@ -0,0 +1,130 @@
+mod common;
+
+use anyhow::Result;
+use common::*;
+
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use type_reflect::*;
+
+#[derive(Reflect, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Plan {
+    pub name: String,
+    pub max_seats: u32,
+    pub annual: Option<bool>,
+}
+
+pub const MAX_UPLOAD: u64 = 10 * 1024 * 1024;
+pub const BETA_FEATURES: bool = true;
+
+fn plans() -> Vec<Plan> {
+    vec![
+        Plan {
+            name: "Free".to_string(),
+            max_seats: 1,
+            annual: None,
+        },
+        Plan {
+            name: "Team".to_string(),
+            max_seats: 25,
+            annual: Some(true),
+        },
+    ]
+}
+
+fn rate_limits() -> HashMap<String, u32> {
+    HashMap::from([("search".to_string(), 10)])
+}
+
+fn trial_days() -> HashMap<String, Option<u32>> {
+    HashMap::from([("Free".to_string(), None)])
+}
+
+pub const SCOPE: &'static str = "test_values";
+
+#[test]
+fn test_typescript_values() -> Result<()> {
+    let output = init_path(SCOPE, "test_typescript_values");
+
+    export_types!(
+        types: [ Plan ],
+        values: [
+            MAX_UPLOAD: u64 = MAX_UPLOAD,
+            BETA_FEATURES: bool = BETA_FEATURES,
+            PLANS: Vec<Plan> = plans(),
+            RATE_LIMITS: HashMap<String, u32> = rate_limits(),
+        ],
+        destinations: [(
+            output.ts_path(),
+            emitters: [
+                TypeScript(),
+                TSValidation(),
+                TSFormat(
+                    tab_size: 2,
+                    line_width: 80,
+                ),
+            ],
+        )]
+    )?;
+
+    let ts = std::fs::read_to_string(output.ts_path())?;
+    assert!(ts.contains("export const MAX_UPLOAD: number = 10485760;"));
+    assert!(ts.contains("export const PLANS: Array<Plan> = ["));
+
+    output.write_jest(
+        "Plan, MAX_UPLOAD, BETA_FEATURES, PLANS, RATE_LIMITS",
+        r#"
+
+describe('Exported Values', ()=>{
+
+  it("exports primitive values", ()=>{
+    expect(MAX_UPLOAD).toEqual(10485760);
+    expect(BETA_FEATURES).toEqual(true);
+  });
+
+  it("exports values of reflected types which pass validation", ()=>{
+    expect(PLANS.length).toEqual(2);
+    expect(() => {
+        Plan.validateArray(PLANS)
+    }).not.toThrow();
+  });
+
+  it("exports maps", ()=>{
+    expect(RATE_LIMITS['search']).toEqual(10);
+  });
+
+})
+    "#,
+    )?;
+
+    output.run_ts()
+}
+
+#[test]
+fn test_zod_values() -> Result<()> {
+    let output = init_path(SCOPE, "test_zod_values");
+
+    export_types!(
+        types: [ Plan ],
+        values: [
+            PLANS: Vec<Plan> = plans(),
+            TRIAL_DAYS: HashMap<String, Option<u32>> = trial_days(),
+        ],
+        destinations: [(
+            output.ts_path(),
+            emitters: [
+                Zod(),
+            ],
+        )]
+    )?;
+
+    let zod = std::fs::read_to_string(output.ts_path())?;
+    assert!(zod.contains(
+        r#"export const PLANS: Array<Plan> = [{"maxSeats":1,"name":"Free"},{"annual":true,"maxSeats":25,"name":"Team"}];"#
+    ));
+    // The `null`s of map values are kept
+    assert!(zod.contains(r#"= {"Free":null};"#));
+
+    Ok(())
+}
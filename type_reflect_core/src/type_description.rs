@@ -1,4 +1,6 @@
-use crate::{Inflectable, Inflection};
+use std::collections::BTreeMap;
+
+use crate::{syn_err, Inflectable, Inflection};
 
 #[derive(Clone, Debug)]
 pub struct NamedType {
@@ -6,11 +8,19 @@ pub struct NamedType {
     pub generic_args: Vec<Box<Type>>,
 }
 
+/**
+The TransparentTypeCase identifies a wrapper which serde serializes as the value it contains
+*/
 #[derive(Clone, Debug)]
 pub enum TransparentTypeCase {
     Box,
     Rc,
     Arc,
+    Mutex,
+    RwLock,
+    Cow,
+    Cell,
+    RefCell,
 }
 
 #[derive(Clone, Debug)]
@@ -19,37 +29,403 @@ pub struct TransparentType {
     pub type_: Box<Type>,
 }
 
+/**
+The StringFormat describes a well-known string encoding used by a third-party type
+
+These are only produced when the corresponding cargo feature is enabled on `type_reflect`
+*/
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum StringFormat {
+    /// An ISO-8601 date-time including an offset, i.e. `chrono::DateTime<Tz>`
+    DateTime,
+    /// An ISO-8601 date-time without an offset, i.e. `chrono::NaiveDateTime`
+    NaiveDateTime,
+    /// An ISO-8601 calendar date, i.e. `chrono::NaiveDate`
+    Date,
+    /// An ISO-8601 time of day, i.e. `chrono::NaiveTime`
+    Time,
+    /// A hyphenated UUID, i.e. `uuid::Uuid`
+    Uuid,
+    /// An absolute URL, i.e. `url::Url`
+    Url,
+    /// A decimal number serialized as a string, i.e. `rust_decimal::Decimal`
+    Decimal,
+}
+
+impl StringFormat {
+    /// A regular expression source matching the string format
+    pub fn pattern(&self) -> &'static str {
+        match self {
+            StringFormat::DateTime => {
+                r"^\d{4}-\d{2}-\d{2}T\d{2}:\d{2}:\d{2}(\.\d+)?(Z|[+-]\d{2}:\d{2})$"
+            }
+            StringFormat::NaiveDateTime => r"^\d{4}-\d{2}-\d{2}T\d{2}:\d{2}:\d{2}(\.\d+)?$",
+            StringFormat::Date => r"^\d{4}-\d{2}-\d{2}$",
+            StringFormat::Time => r"^\d{2}:\d{2}:\d{2}(\.\d+)?$",
+            StringFormat::Uuid => {
+                r"^[0-9a-fA-F]{8}-[0-9a-fA-F]{4}-[0-9a-fA-F]{4}-[0-9a-fA-F]{4}-[0-9a-fA-F]{12}$"
+            }
+            StringFormat::Url => r"^[a-zA-Z][a-zA-Z0-9+.-]*:\S+$",
+            StringFormat::Decimal => r"^-?\d+(\.\d+)?$",
+        }
+    }
+
+    /// A human-readable name for the format, used in error messages
+    pub fn description(&self) -> &'static str {
+        match self {
+            StringFormat::DateTime => "ISO-8601 date-time",
+            StringFormat::NaiveDateTime => "ISO-8601 local date-time",
+            StringFormat::Date => "ISO-8601 date",
+            StringFormat::Time => "ISO-8601 time",
+            StringFormat::Uuid => "UUID",
+            StringFormat::Url => "URL",
+            StringFormat::Decimal => "decimal string",
+        }
+    }
+}
+
+/**
+The TypeOverride holds user-defined target expressions which replace the reflected type
+
+Each emitter falls back to the reflected type when no expression is given for it
+*/
+#[derive(Clone, Debug, Default)]
+pub struct TypeOverride {
+    /// A TypeScript type expression, i.e. `Date`
+    pub type_script: Option<String>,
+    /// A Zod schema expression, i.e. `z.coerce.date()`
+    pub zod: Option<String>,
+    /// A TypeScript function expression which throws if its argument is invalid
+    pub validation: Option<String>,
+}
+
 #[derive(Clone, Debug)]
 pub enum Type {
     Named(NamedType),
     String,
+    FormattedString(StringFormat),
     Int,
     UnsignedInt,
     Float,
     Boolean,
+    /// A type which is always serialized as `null`, i.e. `PhantomData`
+    Null,
     Transparent(TransparentType),
     Option(Box<Type>),
     Array(Box<Type>),
     Map { key: Box<Type>, value: Box<Type> },
+    Override { type_: Box<Type>, overrides: TypeOverride },
+    Inline(InlineType),
+    Constrained { type_: Box<Type>, constraints: Constraints },
+}
+
+/**
+The Constraints restrict the values accepted for a type, beyond what the type itself allows
+
+These are declared on fields with `#[reflect(..)]`, or read from `#[validate(..)]` attributes
+*/
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct Constraints {
+    /// The minimum length of a string or array
+    pub min_len: Option<u64>,
+    /// The maximum length of a string or array
+    pub max_len: Option<u64>,
+    /// The minimum value of a number, inclusive
+    pub min: Option<f64>,
+    /// The maximum value of a number, inclusive
+    pub max: Option<f64>,
+    /// A regular expression source which a string must match
+    pub pattern: Option<String>,
+    /// Requires a string to be an email address
+    pub email: bool,
+    /// Requires a string to be an absolute URL
+    pub url: bool,
+}
+
+impl Constraints {
+    pub fn is_empty(&self) -> bool {
+        self == &Constraints::default()
+    }
+}
+
+/**
+The InlineType describes a struct type whose definition is emitted in place of a reference to it
+
+When emitted by the `Reflect` derive macro, the `fields` and `inflection` are read from
+the `StructType` implementation of the inlined type
+*/
+#[derive(Clone, Debug)]
+pub struct InlineType {
+    pub name: String,
+    pub fields: TypeFieldsDefinition,
+    pub inflection: Inflection,
+}
+
+/**
+The Documentation holds the doc comments and deprecation notice attached to a Rust item
+*/
+#[derive(Clone, Debug, Default)]
+pub struct Documentation {
+    /// The text of the `///` doc comments, with one line per comment line
+    pub description: Option<String>,
+    /// The note from a `#[deprecated]` attribute, which is empty if no note was given
+    pub deprecated: Option<String>,
+}
+
+impl Documentation {
+    pub fn is_empty(&self) -> bool {
+        self.description.is_none() && self.deprecated.is_none()
+    }
+}
+
+/**
+A MetaValue is a literal value attached to an item with `#[reflect(meta(..))]`
+*/
+#[derive(Clone, Debug, PartialEq)]
+pub enum MetaValue {
+    String(String),
+    Int(i64),
+    Float(f64),
+    /// A bare key, i.e. `meta(required)`, is recorded as `Bool(true)`
+    Bool(bool),
+}
+
+impl MetaValue {
+    pub fn as_str(&self) -> Option<&str> {
+        match self {
+            MetaValue::String(value) => Some(value),
+            _ => None,
+        }
+    }
+
+    pub fn as_i64(&self) -> Option<i64> {
+        match self {
+            MetaValue::Int(value) => Some(*value),
+            _ => None,
+        }
+    }
+
+    /// Returns the value as a float, converting integer values
+    pub fn as_f64(&self) -> Option<f64> {
+        match self {
+            MetaValue::Int(value) => Some(*value as f64),
+            MetaValue::Float(value) => Some(*value),
+            _ => None,
+        }
+    }
+
+    pub fn as_bool(&self) -> Option<bool> {
+        match self {
+            MetaValue::Bool(value) => Some(*value),
+            _ => None,
+        }
+    }
+}
+
+/**
+The Metadata holds the user-defined key/value pairs attached to a type, field or variant
+
+It's not used by the built-in emitters, and is intended for custom emitters,
+i.e. to generate validators for a specific library
+*/
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct Metadata(BTreeMap<String, MetaValue>);
+
+impl Metadata {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn with(mut self, key: &str, value: MetaValue) -> Self {
+        self.0.insert(key.to_string(), value);
+        self
+    }
+
+    pub fn insert(&mut self, key: &str, value: MetaValue) {
+        self.0.insert(key.to_string(), value);
+    }
+
+    pub fn get(&self, key: &str) -> Option<&MetaValue> {
+        self.0.get(key)
+    }
+
+    pub fn contains_key(&self, key: &str) -> bool {
+        self.0.contains_key(key)
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+
+    /// Iterates over the entries in key order
+    pub fn iter(&self) -> impl Iterator<Item = (&String, &MetaValue)> {
+        self.0.iter()
+    }
+}
+
+/// The direction a value is converted in: serialized as output, or deserialized from input
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Direction {
+    Serialize,
+    Deserialize,
+}
+
+/// The serde attributes which make a field differ between its serialized
+/// and deserialized forms
+#[derive(Clone, Debug, Default)]
+pub struct FieldDirections {
+    /// The name the field is serialized as, from `rename(serialize = "..")`
+    pub serialize_name: Option<String>,
+    /// The name the field is deserialized from, from `rename(deserialize = "..")`
+    pub deserialize_name: Option<String>,
+    /// Additional names the field is deserialized from, from `alias = ".."`
+    pub aliases: Vec<String>,
+    pub skip_serializing: bool,
+    pub skip_deserializing: bool,
+    /// The field may be omitted from the serialized output, from `skip_serializing_if = ".."`
+    pub skip_serializing_if: bool,
+    /// The field may be omitted from the input, from `default`
+    pub default: bool,
+}
+
+/// How an `Option` field is represented in TypeScript
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum OptionStyle {
+    /// `name?: T`, for fields which are omitted when `None`, i.e. with `skip_serializing_if`
+    #[default]
+    Optional,
+    /// `name: T | null`, for fields which are serialized as `null` when `None`
+    Nullable,
+    /// `name?: T | null`, for fields which may be either omitted or `null`
+    Nullish,
+}
+
+impl OptionStyle {
+    /// Returns true if the field may be omitted
+    pub fn is_optional(&self) -> bool {
+        matches!(self, OptionStyle::Optional | OptionStyle::Nullish)
+    }
+
+    /// Returns true if the field may be `null`
+    pub fn is_nullable(&self) -> bool {
+        matches!(self, OptionStyle::Nullable | OptionStyle::Nullish)
+    }
+}
+
+impl TryFrom<String> for OptionStyle {
+    type Error = syn::Error;
+
+    fn try_from(value: String) -> syn::Result<Self> {
+        Ok(match value.as_str() {
+            "optional" => Self::Optional,
+            "nullable" => Self::Nullable,
+            "nullish" => Self::Nullish,
+            _ => syn_err!(
+                "invalid option style: '{}', expected one of: 'optional', 'nullable', 'nullish'",
+                value
+            ),
+        })
+    }
 }
 
 #[derive(Clone, Debug)]
 pub struct NamedField {
     pub name: String,
+    /// An explicit serialized name, which takes the place of the inflected `name`
+    pub rename: Option<String>,
     pub type_: Type,
+    pub docs: Documentation,
+    pub meta: Metadata,
+    pub directions: FieldDirections,
+    /// The style of an `Option` field, which takes the place of the emitter's style
+    pub option_style: Option<OptionStyle>,
+}
+
+impl NamedField {
+    /// Returns the name of the field as it appears in the serialized output
+    pub fn serialized_name(&self, inflection: Inflection) -> String {
+        match &self.rename {
+            Some(rename) => rename.clone(),
+            None => self.name.inflect(inflection),
+        }
+    }
+
+    /// Returns true if the field appears differently when serialized and deserialized
+    pub fn is_directional(&self) -> bool {
+        let directions = &self.directions;
+        let is_option = matches!(self.type_, Type::Option(_));
+        directions.serialize_name != directions.deserialize_name
+            || directions.skip_serializing != directions.skip_deserializing
+            || (!is_option && directions.skip_serializing_if != directions.default)
+    }
+
+    /// Returns the field as it appears in the given direction, or `None` if it's skipped.
+    ///
+    /// A field which may be omitted in that direction is made optional.
+    pub fn in_direction(&self, direction: Direction) -> Option<NamedField> {
+        let directions = &self.directions;
+        let (skip, rename, optional) = match direction {
+            Direction::Serialize => (
+                directions.skip_serializing,
+                &directions.serialize_name,
+                directions.skip_serializing_if,
+            ),
+            Direction::Deserialize => (
+                directions.skip_deserializing,
+                &directions.deserialize_name,
+                directions.default,
+            ),
+        };
+        if skip {
+            return None;
+        }
+        // A field made optional may be omitted, but is never `null`
+        let (type_, option_style) = match &self.type_ {
+            Type::Option(_) => (self.type_.clone(), self.option_style),
+            type_ if optional => (
+                Type::Option(type_.clone().into()),
+                Some(OptionStyle::Optional),
+            ),
+            type_ => (type_.clone(), self.option_style),
+        };
+        Some(NamedField {
+            rename: rename.clone().or(self.rename.clone()),
+            type_,
+            directions: FieldDirections::default(),
+            option_style,
+            ..self.clone()
+        })
+    }
 }
 
 #[derive(Clone, Debug)]
 pub struct EnumCase {
     pub name: String,
+    /// An explicit serialized name, which takes the place of the inflected `name`
+    pub rename: Option<String>,
     pub type_: TypeFieldsDefinition,
     pub inflection: Inflection,
+    pub docs: Documentation,
+    pub meta: Metadata,
+    /// The value the case is serialized as, for the cases of a `Numeric` enum
+    pub discriminant: Option<i64>,
+    /// Unrecognized variants are deserialized as this case, which is declared with
+    /// `#[serde(other)]` or added by `#[reflect(non_exhaustive)]`
+    pub fallback: bool,
 }
 
 impl EnumCase {
     pub fn name_with_inflection(&self) -> String {
         self.name.inflect(self.inflection)
     }
+
+    /// Returns the name of the case as it appears in the serialized output
+    pub fn serialized_name(&self, inflection: Inflection) -> String {
+        match &self.rename {
+            Some(rename) => rename.clone(),
+            None => self.name.inflect(inflection),
+        }
+    }
 }
 
 /**
@@ -73,9 +449,35 @@ pub enum TypeFieldsDefinition {
     Named(Vec<NamedField>),
 }
 
+impl TypeFieldsDefinition {
+    /// Returns true if any field differs between its serialized and deserialized forms
+    pub fn is_directional(&self) -> bool {
+        match self {
+            TypeFieldsDefinition::Named(fields) => fields.iter().any(NamedField::is_directional),
+            _ => false,
+        }
+    }
+
+    /// Returns the fields as they appear in the given direction
+    pub fn in_direction(&self, direction: Direction) -> TypeFieldsDefinition {
+        match self {
+            TypeFieldsDefinition::Named(fields) => TypeFieldsDefinition::Named(
+                fields
+                    .iter()
+                    .filter_map(|field| field.in_direction(direction))
+                    .collect(),
+            ),
+            fields => fields.clone(),
+        }
+    }
+}
+
 #[derive(Clone, Debug)]
 pub enum EnumType {
     Simple,
+    /// A fieldless enum with an integer `#[repr]`, which is serialized
+    /// as the discriminants of its cases, i.e. with `serde_repr`
+    Numeric,
     Complex {
         case_key: String,
         content_key: Option<String>,
@@ -1,6 +1,9 @@
 use syn::parse::{Parse, ParseStream};
-use syn::{Attribute, Ident, Lit, Result, Token};
+use syn::{Attribute, Expr, ExprLit, Ident, Lit, LitStr, Meta, Result, Token};
 pub use type_reflect_core::inflection::*;
+use type_reflect_core::{
+    Constraints, Documentation, FieldDirections, MetaValue, Metadata, OptionStyle, TypeOverride,
+};
 use type_reflect_core::{impl_parse, syn_err};
 
 #[derive(Default, Clone, Debug)]
@@ -24,9 +27,417 @@ impl RenameAllAttr {
 impl_parse! {
     RenameAllAttr(input, out) {
         "rename_all" => out.rename_all = parse_assign_inflection(input)?,
+        // read by `ContainerAttr`, but accepted so the rest of the attribute isn't ignored
+        "deny_unknown_fields" => {},
     }
 }
 
+/// The `#[reflect(..)]` attributes of a struct, enum or type alias
+#[derive(Default, Clone, Debug)]
+pub struct ContainerAttr {
+    pub export_name: Option<String>,
+    pub rename_all: Option<Inflection>,
+    pub emitters: Option<Vec<String>>,
+    pub skip_emitters: Vec<String>,
+    pub meta: Metadata,
+    pub default: bool,
+    pub numeric: Option<bool>,
+    pub non_exhaustive: bool,
+    /// Set by `#[serde(deny_unknown_fields)]`
+    pub deny_unknown_fields: bool,
+}
+
+impl ContainerAttr {
+    pub fn from_attrs(attrs: &[Attribute]) -> Result<Self> {
+        let mut result = Self::default();
+        parse_reflect_attrs(attrs)?.for_each(|a| result.merge(a));
+        result.deny_unknown_fields = SerdeMemberAttr::from_attrs(attrs).deny_unknown_fields;
+        Ok(result)
+    }
+
+    fn merge(
+        &mut self,
+        ContainerAttr {
+            export_name,
+            rename_all,
+            emitters,
+            skip_emitters,
+            meta,
+            default,
+            numeric,
+            non_exhaustive,
+            deny_unknown_fields,
+        }: ContainerAttr,
+    ) {
+        self.export_name = self.export_name.take().or(export_name);
+        self.rename_all = self.rename_all.take().or(rename_all);
+        self.emitters = self.emitters.take().or(emitters);
+        self.skip_emitters.extend(skip_emitters);
+        merge_meta(&mut self.meta, meta);
+        self.default = self.default || default;
+        self.numeric = self.numeric.take().or(numeric);
+        self.non_exhaustive = self.non_exhaustive || non_exhaustive;
+        self.deny_unknown_fields = self.deny_unknown_fields || deny_unknown_fields;
+    }
+}
+
+impl_parse! {
+    ContainerAttr(input, out) {
+        "export_name" => out.export_name = Some(parse_assign_str(input)?),
+        "rename_all" => out.rename_all = Some(parse_assign_inflection(input)?),
+        "emitters" => out.emitters = Some(parse_ident_list(input)?),
+        "skip_emitters" => out.skip_emitters = parse_ident_list(input)?,
+        "meta" => out.meta = parse_meta(input)?,
+        "default" => out.default = true,
+        "numeric" => out.numeric = Some(parse_flag(input)?),
+        "non_exhaustive" => out.non_exhaustive = true,
+    }
+}
+
+/// The `#[reflect(..)]` attributes of an enum variant
+#[derive(Default, Clone, Debug)]
+pub struct VariantAttr {
+    pub rename: Option<String>,
+    pub rename_all: Option<Inflection>,
+    pub skip: bool,
+    pub meta: Metadata,
+}
+
+impl VariantAttr {
+    pub fn from_attrs(attrs: &[Attribute]) -> Result<Self> {
+        let mut result = Self::default();
+        parse_reflect_attrs(attrs)?.for_each(|a| result.merge(a));
+        Ok(result)
+    }
+
+    fn merge(
+        &mut self,
+        VariantAttr {
+            rename,
+            rename_all,
+            skip,
+            meta,
+        }: VariantAttr,
+    ) {
+        self.rename = self.rename.take().or(rename);
+        self.rename_all = self.rename_all.take().or(rename_all);
+        self.skip = self.skip || skip;
+        merge_meta(&mut self.meta, meta);
+    }
+}
+
+impl_parse! {
+    VariantAttr(input, out) {
+        "rename" => out.rename = Some(parse_assign_str(input)?),
+        "rename_all" => out.rename_all = Some(parse_assign_inflection(input)?),
+        "skip" => out.skip = true,
+        "meta" => out.meta = parse_meta(input)?,
+    }
+}
+
+/// The `#[reflect(..)]` attributes of a struct or variant field
+#[derive(Default, Clone, Debug)]
+pub struct FieldAttr {
+    pub rename: Option<String>,
+    /// Leaves the field out of the output, like `#[serde(skip)]`
+    ///
+    /// `PhantomData` fields are otherwise reflected as `null`, which is how serde writes them.
+    pub skip: bool,
+    pub optional: bool,
+    pub inline: bool,
+    pub type_override: TypeOverride,
+    pub constraints: Constraints,
+    pub meta: Metadata,
+    pub option_style: Option<OptionStyle>,
+}
+
+impl FieldAttr {
+    pub fn from_attrs(attrs: &[Attribute]) -> Result<Self> {
+        let mut result = Self::default();
+        parse_reflect_attrs(attrs)?.for_each(|a| result.merge(a));
+        Ok(result)
+    }
+
+    fn merge(
+        &mut self,
+        FieldAttr {
+            rename,
+            skip,
+            optional,
+            inline,
+            type_override,
+            constraints,
+            meta,
+            option_style,
+        }: FieldAttr,
+    ) {
+        self.rename = self.rename.take().or(rename);
+        self.skip = self.skip || skip;
+        self.optional = self.optional || optional;
+        self.inline = self.inline || inline;
+        let overrides = &mut self.type_override;
+        overrides.type_script = overrides.type_script.take().or(type_override.type_script);
+        overrides.zod = overrides.zod.take().or(type_override.zod);
+        overrides.validation = overrides.validation.take().or(type_override.validation);
+        merge_constraints(&mut self.constraints, constraints);
+        merge_meta(&mut self.meta, meta);
+        self.option_style = self.option_style.take().or(option_style);
+    }
+
+    /// Returns true if any of the emitter overrides are set
+    pub fn has_override(&self) -> bool {
+        self.type_override.type_script.is_some()
+            || self.type_override.zod.is_some()
+            || self.type_override.validation.is_some()
+    }
+}
+
+impl_parse! {
+    FieldAttr(input, out) {
+        "rename" => out.rename = Some(parse_assign_str(input)?),
+        "skip" => out.skip = true,
+        "optional" => out.optional = true,
+        "inline" => out.inline = true,
+        "option_style" => out.option_style = Some(OptionStyle::try_from(parse_assign_str(input)?)?),
+        "type" => out.type_override.type_script = Some(parse_assign_str(input)?),
+        "zod" => out.type_override.zod = Some(parse_assign_str(input)?),
+        "validate" => out.type_override.validation = Some(parse_assign_str(input)?),
+        "min_len" => out.constraints.min_len = Some(parse_assign_u64(input)?),
+        "max_len" => out.constraints.max_len = Some(parse_assign_u64(input)?),
+        "min" => out.constraints.min = Some(parse_assign_number(input)?),
+        "max" => out.constraints.max = Some(parse_assign_number(input)?),
+        "pattern" => out.constraints.pattern = Some(parse_assign_str(input)?),
+        "email" => out.constraints.email = true,
+        "url" => out.constraints.url = true,
+        "meta" => out.meta = parse_meta(input)?,
+    }
+}
+
+/// Sets the constraints from `other` which aren't already set, so the first attribute wins
+pub fn merge_constraints(constraints: &mut Constraints, other: Constraints) {
+    constraints.min_len = constraints.min_len.or(other.min_len);
+    constraints.max_len = constraints.max_len.or(other.max_len);
+    constraints.min = constraints.min.or(other.min);
+    constraints.max = constraints.max.or(other.max);
+    constraints.pattern = constraints.pattern.take().or(other.pattern);
+    constraints.email = constraints.email || other.email;
+    constraints.url = constraints.url || other.url;
+}
+
+/// Reads the constraints from the `validator` crate's `#[validate(..)]` attributes
+///
+/// Only constraints with literal arguments can be reflected, so others, like
+/// `regex(path = ..)` or `custom(..)`, are skipped.
+pub fn parse_validator_constraints(attrs: &[Attribute]) -> Constraints {
+    let mut result = Constraints::default();
+    for attr in attrs.iter().filter(|a| a.path().is_ident("validate")) {
+        let _ = attr.parse_nested_meta(|meta| {
+            if meta.path.is_ident("email") {
+                result.email = true;
+            } else if meta.path.is_ident("url") {
+                result.url = true;
+            } else if meta.path.is_ident("length") {
+                meta.parse_nested_meta(|length| {
+                    let value = literal_number(&length.value()?.parse::<Expr>()?);
+                    let value = value.map(|value| value as u64);
+                    if length.path.is_ident("min") {
+                        result.min_len = value;
+                    } else if length.path.is_ident("max") {
+                        result.max_len = value;
+                    } else if length.path.is_ident("equal") {
+                        result.min_len = value;
+                        result.max_len = value;
+                    }
+                    Ok(())
+                })?;
+            } else if meta.path.is_ident("range") {
+                meta.parse_nested_meta(|range| {
+                    let value = literal_number(&range.value()?.parse::<Expr>()?);
+                    if range.path.is_ident("min") {
+                        result.min = value;
+                    } else if range.path.is_ident("max") {
+                        result.max = value;
+                    }
+                    Ok(())
+                })?;
+            } else if meta.input.peek(Token![=]) {
+                meta.value()?.parse::<Expr>()?;
+            } else if meta.input.peek(syn::token::Paren) {
+                meta.input.parse::<proc_macro2::Group>()?;
+            }
+            Ok(())
+        });
+    }
+    result
+}
+
+/// Returns the value of an integer or float literal, which may be negated
+fn literal_number(expr: &Expr) -> Option<f64> {
+    match expr {
+        Expr::Lit(ExprLit {
+            lit: Lit::Int(value),
+            ..
+        }) => value.base10_parse().ok(),
+        Expr::Lit(ExprLit {
+            lit: Lit::Float(value),
+            ..
+        }) => value.base10_parse().ok(),
+        Expr::Unary(syn::ExprUnary {
+            op: syn::UnOp::Neg(_),
+            expr,
+            ..
+        }) => literal_number(expr).map(|value| -value),
+        _ => None,
+    }
+}
+
+/// The `#[serde(..)]` attributes of a container, field or variant which change its serialized form
+///
+/// Unlike `parse_serde_attrs`, unrelated keys in the same attribute are skipped
+/// rather than causing the whole attribute to be ignored.
+#[derive(Default, Clone, Debug)]
+pub struct SerdeMemberAttr {
+    pub rename: Option<String>,
+    pub skip: bool,
+    pub other: bool,
+    pub deny_unknown_fields: bool,
+    pub directions: FieldDirections,
+    /// The module given with `#[serde(with = "..")]`
+    pub with: Option<String>,
+}
+
+impl SerdeMemberAttr {
+    pub fn from_attrs(attrs: &[Attribute]) -> Self {
+        let mut result = Self::default();
+        for attr in attrs.iter().filter(|a| a.path().is_ident("serde")) {
+            let _ = attr.parse_nested_meta(|meta| {
+                if meta.path.is_ident("rename") && meta.input.peek(Token![=]) {
+                    let rename: LitStr = meta.value()?.parse()?;
+                    result.rename = Some(rename.value());
+                } else if meta.path.is_ident("rename") {
+                    meta.parse_nested_meta(|meta| {
+                        let rename: LitStr = meta.value()?.parse()?;
+                        if meta.path.is_ident("serialize") {
+                            result.directions.serialize_name = Some(rename.value());
+                        } else if meta.path.is_ident("deserialize") {
+                            result.directions.deserialize_name = Some(rename.value());
+                        }
+                        Ok(())
+                    })?;
+                } else if meta.path.is_ident("alias") {
+                    let alias: LitStr = meta.value()?.parse()?;
+                    result.directions.aliases.push(alias.value());
+                } else if meta.path.is_ident("skip") {
+                    result.skip = true;
+                } else if meta.path.is_ident("skip_serializing") {
+                    result.directions.skip_serializing = true;
+                } else if meta.path.is_ident("skip_deserializing") {
+                    result.directions.skip_deserializing = true;
+                } else if meta.path.is_ident("skip_serializing_if") {
+                    meta.value()?.parse::<Expr>()?;
+                    result.directions.skip_serializing_if = true;
+                } else if meta.path.is_ident("default") {
+                    if meta.input.peek(Token![=]) {
+                        meta.value()?.parse::<Expr>()?;
+                    }
+                    result.directions.default = true;
+                } else if meta.path.is_ident("other") {
+                    result.other = true;
+                } else if meta.path.is_ident("deny_unknown_fields") {
+                    result.deny_unknown_fields = true;
+                } else if meta.path.is_ident("with") {
+                    let with: LitStr = meta.value()?.parse()?;
+                    result.with = Some(with.value());
+                } else if meta.input.peek(Token![=]) {
+                    meta.value()?.parse::<Expr>()?;
+                } else if meta.input.peek(syn::token::Paren) {
+                    meta.input.parse::<proc_macro2::Group>()?;
+                }
+                Ok(())
+            });
+        }
+        result
+    }
+}
+
+/// Parse the `///` doc comments and `#[deprecated]` attribute from the given slice.
+pub fn parse_docs(attrs: &[Attribute]) -> Result<Documentation> {
+    let mut lines: Vec<String> = vec![];
+    let mut deprecated: Option<String> = None;
+
+    for attr in attrs {
+        if attr.path().is_ident("doc") {
+            if let Meta::NameValue(meta) = &attr.meta
+                && let Expr::Lit(ExprLit {
+                    lit: Lit::Str(doc), ..
+                }) = &meta.value
+            {
+                lines.extend(doc.value().split('\n').map(|line| line.trim_end().to_string()));
+            }
+        } else if attr.path().is_ident("deprecated") {
+            deprecated = Some(parse_deprecation_note(attr)?);
+        }
+    }
+
+    Ok(Documentation {
+        description: join_doc_lines(lines),
+        deprecated,
+    })
+}
+
+fn parse_deprecation_note(attr: &Attribute) -> Result<String> {
+    match &attr.meta {
+        Meta::Path(_) => Ok(String::new()),
+        Meta::NameValue(meta) => match &meta.value {
+            Expr::Lit(ExprLit {
+                lit: Lit::Str(note),
+                ..
+            }) => Ok(note.value()),
+            _ => Ok(String::new()),
+        },
+        Meta::List(_) => {
+            let mut note = String::new();
+            attr.parse_nested_meta(|meta| {
+                let value: LitStr = meta.value()?.parse()?;
+                if meta.path.is_ident("note") {
+                    note = value.value();
+                }
+                Ok(())
+            })?;
+            Ok(note)
+        }
+    }
+}
+
+/// Joins doc comment lines, removing the indentation they have in common
+/// and any leading or trailing blank lines
+fn join_doc_lines(lines: Vec<String>) -> Option<String> {
+    let indent = lines
+        .iter()
+        .filter(|line| !line.trim().is_empty())
+        .map(|line| line.len() - line.trim_start().len())
+        .min()?;
+    let lines: Vec<&str> = lines
+        .iter()
+        .map(|line| line.get(indent..).unwrap_or(""))
+        .collect();
+    let text = lines.join("\n").trim_matches('\n').to_string();
+    Some(text)
+}
+
+/// Parse all `#[reflect(..)]` attributes from the given slice.
+pub fn parse_reflect_attrs<'a, A>(attrs: &'a [Attribute]) -> Result<impl Iterator<Item = A>>
+where
+    A: TryFrom<&'a Attribute, Error = syn::Error>,
+{
+    Ok(attrs
+        .iter()
+        .filter(|a| a.path().is_ident("reflect"))
+        .map(A::try_from)
+        .collect::<Result<Vec<A>>>()?
+        .into_iter())
+}
+
 /// Parse all `#[ts(..)]` attributes from the given slice.
 pub fn parse_attrs<'a, A>(attrs: &'a [Attribute]) -> Result<impl Iterator<Item = A>>
 where
@@ -74,6 +485,93 @@ pub fn parse_assign_str(input: ParseStream) -> Result<String> {
     }
 }
 
+/// Adds the entries of `other` which aren't already set, so the first attribute wins
+fn merge_meta(meta: &mut Metadata, other: Metadata) {
+    for (key, value) in other.iter() {
+        if !meta.contains_key(key) {
+            meta.insert(key, value.clone());
+        }
+    }
+}
+
+/// Parse a parenthesized list of metadata entries, i.e. `(key = "value", min = 3, flag)`
+pub fn parse_meta(input: ParseStream) -> Result<Metadata> {
+    let content;
+    syn::parenthesized!(content in input);
+    let mut meta = Metadata::new();
+    while !content.is_empty() {
+        let key: Ident = content.call(syn::ext::IdentExt::parse_any)?;
+        let value = match content.peek(Token![=]) {
+            true => {
+                content.parse::<Token![=]>()?;
+                parse_meta_value(&content)?
+            }
+            false => MetaValue::Bool(true),
+        };
+        meta.insert(&key.to_string(), value);
+        if !content.is_empty() {
+            content.parse::<Token![,]>()?;
+        }
+    }
+    Ok(meta)
+}
+
+fn parse_meta_value(input: ParseStream) -> Result<MetaValue> {
+    let negative = input.parse::<Option<Token![-]>>()?.is_some();
+    match (Lit::parse(input)?, negative) {
+        (Lit::Str(value), false) => Ok(MetaValue::String(value.value())),
+        (Lit::Bool(value), false) => Ok(MetaValue::Bool(value.value)),
+        (Lit::Int(value), negative) => {
+            let value: i64 = value.base10_parse()?;
+            Ok(MetaValue::Int(if negative { -value } else { value }))
+        }
+        (Lit::Float(value), negative) => {
+            let value: f64 = value.base10_parse()?;
+            Ok(MetaValue::Float(if negative { -value } else { value }))
+        }
+        (other, _) => Err(syn::Error::new(
+            other.span(),
+            "expected a string, number or boolean",
+        )),
+    }
+}
+
+/// Parses a flag which is either bare, meaning `true`, or assigned a boolean, i.e. `numeric = false`
+pub fn parse_flag(input: ParseStream) -> Result<bool> {
+    if !input.peek(Token![=]) {
+        return Ok(true);
+    }
+    input.parse::<Token![=]>()?;
+    match Lit::parse(input)? {
+        Lit::Bool(value) => Ok(value.value),
+        other => Err(syn::Error::new(other.span(), "expected a boolean")),
+    }
+}
+
+/// Parse a parenthesized list of identifiers, i.e. `(TypeScript, Zod)`
+pub fn parse_ident_list(input: ParseStream) -> Result<Vec<String>> {
+    let content;
+    syn::parenthesized!(content in input);
+    let idents = content.parse_terminated(Ident::parse, Token![,])?;
+    Ok(idents.iter().map(|ident| ident.to_string()).collect())
+}
+
+pub fn parse_assign_u64(input: ParseStream) -> Result<u64> {
+    input.parse::<Token![=]>()?;
+    match Lit::parse(input)? {
+        Lit::Int(value) => value.base10_parse(),
+        other => Err(syn::Error::new(other.span(), "expected an integer")),
+    }
+}
+
+pub fn parse_assign_number(input: ParseStream) -> Result<f64> {
+    input.parse::<Token![=]>()?;
+    match parse_meta_value(input)?.as_f64() {
+        Some(value) => Ok(value),
+        None => Err(input.error("expected a number")),
+    }
+}
+
 pub fn parse_assign_inflection(input: ParseStream) -> Result<Inflection> {
     match parse_assign_str(input) {
         Ok(str) => Inflection::try_from(str),
@@ -4,7 +4,7 @@ use syn::parse::{Parse, ParseStream};
 use syn::token::Paren;
 use syn::*;
 
-use super::{peak_arg_name, DestinationArg, NamedArg};
+use super::{peak_arg_name, DestinationArg, NamedArg, ValueList};
 
 #[derive(Debug, Clone)]
 pub enum Destination {
@@ -217,14 +217,18 @@ impl Parse for UnnamedDestination {
     }
 }
 
-pub fn emit_destination(dest: &Destination, types: &Vec<&Ident>) -> TokenStream {
+pub fn emit_destination(dest: &Destination, types: &Vec<&Ident>, values: &ValueList) -> TokenStream {
     match dest {
-        Destination::Named(dest) => emit_named_destination(dest, types),
-        Destination::Unnamed(dest) => emit_unnamed_destination(dest, types),
+        Destination::Named(dest) => emit_named_destination(dest, types, values),
+        Destination::Unnamed(dest) => emit_unnamed_destination(dest, types, values),
     }
 }
 
-pub fn emit_named_destination(dest: &NamedDestination, types: &Vec<&Ident>) -> TokenStream {
+pub fn emit_named_destination(
+    dest: &NamedDestination,
+    types: &Vec<&Ident>,
+    values: &ValueList,
+) -> TokenStream {
     let emitter = &dest.export_type;
 
     let prefix = match &dest.prefix {
@@ -247,13 +251,16 @@ pub fn emit_named_destination(dest: &NamedDestination, types: &Vec<&Ident>) -> T
                 #emitter_args
                 ..Default::default()
             };
-            file.write_all(emitter.prefix().as_bytes())?;
+            let prefix = emitter.prefix();
+            type_reflect::check_fragment(&mut emitter, "prefix", &prefix)?;
+            file.write_all(prefix.as_bytes())?;
         });
         for type_ in types {
             result.extend(quote! {
-                file.write_all(emitter.emit::<#type_>().as_bytes())?;
+                file.write_all(emitter.emit_checked::<#type_>()?.as_bytes())?;
             });
         }
+        result.extend(values.emit_writes());
         result.extend(quote! {
             emitter.finalize(#dest)?;
         });
@@ -264,7 +271,12 @@ pub fn emit_named_destination(dest: &NamedDestination, types: &Vec<&Ident>) -> T
     result
 }
 
-pub fn emit_single_emitter(emitter: &EmitterDecl, types: &Vec<&Ident>, dest: &Expr) -> TokenStream {
+pub fn emit_single_emitter(
+    emitter: &EmitterDecl,
+    types: &Vec<&Ident>,
+    values: &ValueList,
+    dest: &Expr,
+) -> TokenStream {
     let emitter_name = &emitter.type_name;
 
     let emitter_args = &emitter.args;
@@ -276,13 +288,16 @@ pub fn emit_single_emitter(emitter: &EmitterDecl, types: &Vec<&Ident>, dest: &Ex
             #emitter_args
             ..Default::default()
         };
-        file.write_all(emitter.prefix().as_bytes())?;
+        let prefix = emitter.prefix();
+        type_reflect::check_fragment(&mut emitter, "prefix", &prefix)?;
+        file.write_all(prefix.as_bytes())?;
     });
     for type_ in types {
         result.extend(quote! {
-            file.write_all(emitter.emit::<#type_>().as_bytes())?;
+            file.write_all(emitter.emit_checked::<#type_>()?.as_bytes())?;
         });
     }
+    result.extend(values.emit_writes());
     result.extend(quote! {
         emitter.finalize(#dest)?;
     });
@@ -290,7 +305,11 @@ pub fn emit_single_emitter(emitter: &EmitterDecl, types: &Vec<&Ident>, dest: &Ex
     result
 }
 
-pub fn emit_unnamed_destination(dest: &UnnamedDestination, types: &Vec<&Ident>) -> TokenStream {
+pub fn emit_unnamed_destination(
+    dest: &UnnamedDestination,
+    types: &Vec<&Ident>,
+    values: &ValueList,
+) -> TokenStream {
     let prefix = match &dest.prefix {
         Some(expr) => {
             quote! { #expr }
@@ -314,7 +333,7 @@ pub fn emit_unnamed_destination(dest: &UnnamedDestination, types: &Vec<&Ident>)
                     .append(true)
                     .open(#dest)?;
             });
-            result.extend(emit_single_emitter(emitter, types, dest));
+            result.extend(emit_single_emitter(emitter, types, values, dest));
         }
         if let Some(expr) = postfix { result.extend(quote! {
             type_reflect::write_postfix(#dest, #expr)?;
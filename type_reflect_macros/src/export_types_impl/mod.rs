@@ -6,6 +6,10 @@ use syn::token::Bracket;
 use syn::*;
 mod destination;
 use destination::*;
+mod type_overrides;
+use type_overrides::*;
+mod values;
+use values::*;
 
 #[derive(Debug, Clone)]
 struct ItemsList {
@@ -148,6 +152,8 @@ impl Parse for DestinationArg {
 #[derive(Debug, Clone)]
 struct Input {
     items: ItemsList,
+    type_overrides: TypeOverrideList,
+    values: ValueList,
     destinations: DestinationList,
 }
 
@@ -155,9 +161,25 @@ impl Parse for Input {
     fn parse(input: ParseStream) -> Result<Self> {
         let items = input.parse()?;
         let _comma_token: Token![,] = input.parse()?;
+        let mut type_overrides = TypeOverrideList::default();
+        if let Some(name) = peak_arg_name(&input)
+            && name == "type_overrides"
+        {
+            type_overrides = input.parse()?;
+            let _comma_token: Token![,] = input.parse()?;
+        }
+        let mut values = ValueList::default();
+        if let Some(name) = peak_arg_name(&input)
+            && name == "values"
+        {
+            values = input.parse()?;
+            let _comma_token: Token![,] = input.parse()?;
+        }
         let destinations = input.parse()?;
         Ok(Self {
             items,
+            type_overrides,
+            values,
             destinations,
         })
     }
@@ -171,9 +193,10 @@ pub fn export_types_impl(input: proc_macro::TokenStream) -> Result<TokenStream>
     let types = input.items.args();
     let destinations = input.destinations.destinations;
 
-    let mut result = quote! {};
+    let mut result = input.type_overrides.emit(&types);
+    result.extend(input.values.emit()?);
     for dest in destinations {
-        result.extend(emit_destination(&dest, &types))
+        result.extend(emit_destination(&dest, &types, &input.values))
     }
 
     let result = quote! {
@@ -0,0 +1,127 @@
+use proc_macro2::*;
+use quote::*;
+use syn::ext::IdentExt;
+use syn::parse::{Parse, ParseStream};
+use syn::token::{Bracket, Paren};
+use syn::*;
+
+#[derive(Debug, Clone)]
+pub struct TypeOverrideDecl {
+    pub type_name: Ident,
+    pub type_script: Option<Expr>,
+    pub zod: Option<Expr>,
+    pub validation: Option<Expr>,
+}
+
+impl Parse for TypeOverrideDecl {
+    fn parse(input: ParseStream) -> Result<Self> {
+        let type_name: Ident = input.parse()?;
+        let _colon_token: Token![:] = input.parse()?;
+
+        let content;
+        let _parens: Paren = parenthesized!(content in input);
+
+        let mut decl = Self {
+            type_name,
+            type_script: None,
+            zod: None,
+            validation: None,
+        };
+
+        while !content.is_empty() {
+            let key: Ident = content.call(Ident::parse_any)?;
+            let _colon_token: Token![:] = content.parse()?;
+            let expr: Expr = content.parse()?;
+            match key.to_string().as_str() {
+                "type" => decl.type_script = Some(expr),
+                "zod" => decl.zod = Some(expr),
+                "validate" => decl.validation = Some(expr),
+                other => {
+                    return Err(syn::Error::new(
+                        key.span(),
+                        format!(
+                            r#"Unexpected type override: "{}", expected one of: "type", "zod", "validate""#,
+                            other
+                        ),
+                    ));
+                }
+            }
+            if content.peek(Token![,]) {
+                let _comma: Token![,] = content.parse()?;
+            }
+        }
+
+        Ok(decl)
+    }
+}
+
+fn emit_option_expr(expr: &Option<Expr>) -> TokenStream {
+    match expr {
+        Some(expr) => quote! { Some((#expr).to_string()) },
+        None => quote! { None },
+    }
+}
+
+impl TypeOverrideDecl {
+    pub fn emit(&self) -> TokenStream {
+        let type_name = self.type_name.to_string();
+        let type_script = emit_option_expr(&self.type_script);
+        let zod = emit_option_expr(&self.zod);
+        let validation = emit_option_expr(&self.validation);
+        quote! {
+            .with(#type_name, type_reflect::TypeOverride {
+                type_script: #type_script,
+                zod: #zod,
+                validation: #validation,
+            })
+        }
+    }
+}
+
+#[derive(Debug, Clone, Default)]
+pub struct TypeOverrideList {
+    pub overrides: Vec<TypeOverrideDecl>,
+}
+
+impl Parse for TypeOverrideList {
+    fn parse(input: ParseStream) -> Result<Self> {
+        let ident: Ident = input.parse()?;
+        if ident.to_string().as_str() != "type_overrides" {
+            return Err(syn::Error::new(
+                ident.span(),
+                r#"Expected argument name: "type_overrides""#,
+            ));
+        }
+        let _colon_token: Token![:] = input.parse()?;
+        let content;
+        let _brackets: Bracket = bracketed!(content in input);
+        let overrides = content.parse_terminated(TypeOverrideDecl::parse, Token![,])?;
+        Ok(Self {
+            overrides: overrides.into_iter().collect(),
+        })
+    }
+}
+
+impl TypeOverrideList {
+    /// Emits a guard which installs the overrides, and the export names and definitions
+    /// of the exported types, for the remainder of the export
+    pub fn emit(&self, types: &Vec<&Ident>) -> TokenStream {
+        let overrides: Vec<TokenStream> = self.overrides.iter().map(|o| o.emit()).collect();
+        let export_names: Vec<TokenStream> = types
+            .iter()
+            .map(|type_| {
+                let type_name = type_.to_string();
+                quote! {
+                    .with_export_name::<#type_>(#type_name)
+                    .with_definition::<#type_>(#type_name)
+                }
+            })
+            .collect();
+        quote! {
+            let _type_overrides = type_reflect::TypeOverrides::new()
+                #(#overrides)*
+                #(#export_names)*
+                .install();
+        }
+    }
+}
@@ -0,0 +1,96 @@
+use proc_macro2::*;
+use quote::*;
+use syn::parse::{Parse, ParseStream};
+use syn::token::Bracket;
+use syn::*;
+
+use crate::type_def::syn_type_utils::SynTypeBridge;
+use crate::type_def::type_utils::TypeBridge;
+
+/// A value declared in the `values` section of `export_types!`, i.e. `MAX_UPLOAD: u64 = MAX_UPLOAD`
+#[derive(Debug, Clone)]
+pub struct ValueDecl {
+    pub name: Ident,
+    pub type_: syn::Type,
+    pub expr: Expr,
+}
+
+impl Parse for ValueDecl {
+    fn parse(input: ParseStream) -> Result<Self> {
+        let name: Ident = input.parse()?;
+        let _colon_token: Token![:] = input.parse()?;
+        let type_: syn::Type = input.parse()?;
+        let _eq_token: Token![=] = input.parse()?;
+        let expr: Expr = input.parse()?;
+        Ok(Self { name, type_, expr })
+    }
+}
+
+impl ValueDecl {
+    pub fn emit(&self) -> Result<TokenStream> {
+        let name = self.name.to_string();
+        let type_ = self.type_.to_type()?.emit_type();
+        let expr = &self.expr;
+        let rust_type = &self.type_;
+        Ok(quote! {
+            (#name, #type_, type_reflect::serde_json::to_value::<#rust_type>(#expr)?)
+        })
+    }
+}
+
+#[derive(Debug, Clone, Default)]
+pub struct ValueList {
+    pub values: Vec<ValueDecl>,
+}
+
+impl Parse for ValueList {
+    fn parse(input: ParseStream) -> Result<Self> {
+        let ident: Ident = input.parse()?;
+        if ident.to_string().as_str() != "values" {
+            return Err(syn::Error::new(
+                ident.span(),
+                r#"Expected argument name: "values""#,
+            ));
+        }
+        let _colon_token: Token![:] = input.parse()?;
+        let content;
+        let _brackets: Bracket = bracketed!(content in input);
+        let values = content.parse_terminated(ValueDecl::parse, Token![,])?;
+        Ok(Self {
+            values: values.into_iter().collect(),
+        })
+    }
+}
+
+impl ValueList {
+    /// Emits the serialized values, which are evaluated once for all the destinations
+    pub fn emit(&self) -> Result<TokenStream> {
+        if self.values.is_empty() {
+            return Ok(quote! {});
+        }
+        let values = self
+            .values
+            .iter()
+            .map(|value| value.emit())
+            .collect::<Result<Vec<TokenStream>>>()?;
+        Ok(quote! {
+            let _values: Vec<(&str, Type, type_reflect::serde_json::Value)> = vec![
+                #(#values),*
+            ];
+        })
+    }
+
+    /// Emits the calls which write the values to the current destination `file`
+    pub fn emit_writes(&self) -> TokenStream {
+        if self.values.is_empty() {
+            return quote! {};
+        }
+        quote! {
+            for (name, type_, value) in &_values {
+                let fragment = emitter.emit_value(name, type_, value);
+                type_reflect::check_fragment(&mut emitter, name, &fragment)?;
+                file.write_all(fragment.as_bytes())?;
+            }
+        }
+    }
+}
@@ -4,7 +4,13 @@ use crate::type_def::InflectionTokenProvider;
 use type_reflect_core::EnumType;
 use type_reflect_core::Inflection;
 
-use super::{syn_type_utils::*, type_utils::TypeFieldsDefinitionBridge, RustTypeEmitter};
+use super::{
+    syn_type_utils::*,
+    type_utils::{
+        emit_option_string, DocumentationBridge, MetadataBridge, TypeFieldsDefinitionBridge,
+    },
+    RustTypeEmitter,
+};
 use proc_macro2::{Ident, TokenStream};
 use quote::quote;
 use syn::{
@@ -22,12 +28,25 @@ pub struct EnumDef {
     pub enum_type: EnumType,
     pub inflection: Inflection,
     pub cases: Vec<EnumCase>,
+    pub docs: Documentation,
+    pub attributes: ContainerAttr,
+    /// The integer type given with `#[repr(..)]`, if any
+    pub repr: Option<Ident>,
 }
 
 fn extract_cases(item: &ItemEnum) -> Result<Vec<EnumCase>> {
-    (&item.variants)
+    let mut variants = vec![];
+    for case in &item.variants {
+        let attr = VariantAttr::from_attrs(&case.attrs)?;
+        let serde_attr = SerdeMemberAttr::from_attrs(&case.attrs);
+        if !attr.skip && !serde_attr.skip {
+            variants.push((case, attr, serde_attr));
+        }
+    }
+
+    variants
         .into_iter()
-        .map(|case| {
+        .map(|(case, attr, serde_attr)| {
             let name = format!("{}", case.ident);
             let inflection: Inflection = match RenameAllAttr::from_attrs(&case.attrs) {
                 Err(e) => {
@@ -45,10 +64,18 @@ fn extract_cases(item: &ItemEnum) -> Result<Vec<EnumCase>> {
                     rename_all.rename_all
                 }
             };
+            if serde_attr.other && !matches!(case.fields, syn::Fields::Unit) {
+                syn_err!(case.ident.span(); "only a unit variant can be declared with #[serde(other)]");
+            }
             Ok(EnumCase {
                 name,
+                rename: attr.rename.or(serde_attr.rename),
                 type_: case.fields.to_fields()?,
-                inflection,
+                inflection: attr.rename_all.unwrap_or(inflection),
+                docs: parse_docs(&case.attrs)?,
+                meta: attr.meta,
+                discriminant: None,
+                fallback: serde_attr.other,
             })
         })
         .collect()
@@ -58,15 +85,23 @@ impl EnumDef {
     pub fn new(item: &ItemEnum) -> Result<Self> {
         let attributes = EnumAttr::from_attrs(&item.attrs)?;
         let rename_attr = RenameAllAttr::from_attrs(&item.attrs)?;
+        let container_attr = ContainerAttr::from_attrs(&item.attrs)?;
 
-        let cases = extract_cases(item)?;
+        let mut cases = extract_cases(item)?;
 
-        let enum_type = match cases.iter().any(|case| if let TypeFieldsDefinition::Unit = case.type_ {
-                    false
-                } else {
-                    true
-                }) {
+        let is_complex = cases
+            .iter()
+            .any(|case| !matches!(case.type_, TypeFieldsDefinition::Unit));
+
+        // A `repr` alone doesn't change how serde serializes the enum, so numeric is opt-in
+        let numeric = container_attr.numeric.unwrap_or(false);
+        if numeric && is_complex {
+            syn_err!(item.ident.span(); "a numeric enum can't have variants with fields");
+        }
+
+        let enum_type = match is_complex {
             // false indicates it is not complex
+            false if numeric => EnumType::Numeric,
             false => EnumType::Simple,
             // true indicates the type is complex
             true => match attributes.tag {
@@ -81,12 +116,47 @@ impl EnumDef {
             },
         };
 
+        let inflection = container_attr.rename_all.unwrap_or(rename_attr.rename_all);
+
+        // The added case is always serialized as `Unknown`, regardless of `rename_all`
+        if container_attr.non_exhaustive && !cases.iter().any(|case| case.fallback) {
+            if !matches!(enum_type, EnumType::Simple | EnumType::Complex { .. }) {
+                syn_err!(item.ident.span(); "non_exhaustive is only supported for string and tagged enums");
+            }
+            if let Some(case) = cases
+                .iter()
+                .find(|case| case.name == "Unknown" || case.serialized_name(inflection) == "Unknown")
+            {
+                syn_err!(
+                    item.ident.span();
+                    "non_exhaustive adds an `Unknown` variant, which collides with the variant `{}`; declare the fallback with #[serde(other)] instead",
+                    case.name
+                );
+            }
+            cases.push(EnumCase {
+                name: "Unknown".to_string(),
+                rename: Some("Unknown".to_string()),
+                type_: TypeFieldsDefinition::Unit,
+                inflection: Inflection::None,
+                docs: Documentation {
+                    description: Some("An unrecognized variant".to_string()),
+                    deprecated: None,
+                },
+                meta: Metadata::default(),
+                discriminant: None,
+                fallback: true,
+            });
+        }
+
         Ok(Self {
             tokens: quote! { #item },
             ident: item.ident.clone(),
             enum_type,
-            inflection: rename_attr.rename_all,
+            inflection,
             cases,
+            docs: parse_docs(&item.attrs)?,
+            attributes: container_attr,
+            repr: integer_repr(&item.attrs)?,
         })
     }
 
@@ -95,13 +165,31 @@ impl EnumDef {
             .iter()
             .map(|case| {
                 let name = &case.name;
+                let rename = emit_option_string(&case.rename);
                 let type_ = case.type_.emit_def();
                 let rename_all = &case.inflection.to_tokens();
+                let docs = case.docs.emit_documentation();
+                let meta = case.meta.emit_metadata();
+                let fallback = case.fallback;
+                let discriminant = match self.enum_type {
+                    EnumType::Numeric => {
+                        let ident = &self.ident;
+                        let case_ident: Ident =
+                            syn::parse_str(name).expect("a variant name is a valid identifier");
+                        quote! { Some(#ident::#case_ident as i64) }
+                    }
+                    _ => quote! { None },
+                };
                 quote! {
                     EnumCase {
                         name: #name.to_string(),
+                        rename: #rename,
                         type_: #type_,
                         inflection: #rename_all,
+                        docs: #docs,
+                        meta: #meta,
+                        discriminant: #discriminant,
+                        fallback: #fallback,
                     }
                 }
             })
@@ -111,14 +199,55 @@ impl EnumDef {
         }
     }
 
+    /// Emits compile-time checks that the discriminants of a numeric enum fit in the `i64`
+    /// they're reflected as, since the cast in `emit_cases` would silently wrap
+    fn emit_discriminant_checks(&self) -> TokenStream {
+        let ident = &self.ident;
+        let repr = match (&self.enum_type, &self.repr) {
+            (EnumType::Numeric, Some(repr)) => repr.to_string(),
+            _ => return quote! {},
+        };
+        let checks: Vec<TokenStream> = self
+            .cases
+            .iter()
+            .map(|case| {
+                let case_ident: Ident =
+                    syn::parse_str(&case.name).expect("a variant name is a valid identifier");
+                let message = format!(
+                    "the discriminant of {ident}::{} doesn't fit in an i64, so it can't be reflected",
+                    case.name
+                );
+                let fits = match repr.as_str() {
+                    "u64" | "u128" | "usize" => quote! {
+                        (#ident::#case_ident as u128) <= i64::MAX as u128
+                    },
+                    "i128" => quote! {
+                        (#ident::#case_ident as i128) >= i64::MIN as i128
+                            && (#ident::#case_ident as i128) <= i64::MAX as i128
+                    },
+                    _ => return quote! {},
+                };
+                quote! {
+                    const _: () = assert!(#fits, #message);
+                }
+            })
+            .collect();
+        quote! {
+            #(#checks)*
+        }
+    }
+
     pub fn emit(&self) -> TokenStream {
         let ident = &self.ident();
-        let name_literal = format!("{}", ident);
+        let name_literal = self.attributes.export_name(ident);
+        let emittable_items = self.attributes.emit_emittable_items();
+        let emitter_filter = self.attributes.emit_emitter_filter();
         let cases = &self.emit_cases();
         let rust = format!("{}", self.tokens());
 
         let enum_type = match &self.enum_type {
             EnumType::Simple => quote! {EnumType::Simple},
+            EnumType::Numeric => quote! {EnumType::Numeric},
             EnumType::Complex {
                 case_key,
                 content_key,
@@ -137,15 +266,31 @@ impl EnumDef {
         };
 
         let inflection = &self.inflection.to_tokens();
+        let docs = &self.docs.emit_documentation();
+        let meta = self.attributes.meta.emit_metadata();
+        let default_value = self.attributes.emit_default_value();
+        let deny_unknown_fields = self.attributes.emit_deny_unknown_fields();
+        let discriminant_checks = self.emit_discriminant_checks();
 
         quote! {
 
+            #discriminant_checks
+
+
+            #[allow(deprecated)]
             impl Emittable for #ident {
+                #emittable_items
+                fn definition() -> Option<Definition> {
+                    Some(Definition::of_enum::<Self>())
+                }
                 fn emit_with<E: TypeEmitter>(emitter: &mut E) -> String {
+                    #emitter_filter
                     emitter.emit_enum::<Self>()
                 }
             }
 
+
+            #[allow(deprecated)]
             impl EnumReflectionType for #ident {
                 fn name() -> &'static str {
                     #name_literal
@@ -164,6 +309,14 @@ impl EnumDef {
                 fn rust() -> String {
                     #rust.to_string()
                 }
+                fn docs() -> Documentation {
+                    #docs
+                }
+                fn meta() -> Metadata {
+                    #meta
+                }
+                #default_value
+                #deny_unknown_fields
             }
 
         }
@@ -179,6 +332,30 @@ impl RustTypeEmitter for EnumDef {
     }
 }
 
+/// Returns the integer type the enum is declared with, i.e. `u8` for `#[repr(u8)]`
+fn integer_repr(attrs: &[Attribute]) -> Result<Option<Ident>> {
+    const INTEGER_TYPES: [&str; 12] = [
+        "u8", "u16", "u32", "u64", "u128", "usize", "i8", "i16", "i32", "i64", "i128", "isize",
+    ];
+    let mut result = None;
+    for attr in attrs.iter().filter(|attr| attr.path().is_ident("repr")) {
+        attr.parse_nested_meta(|meta| {
+            if let Some(ident) = meta.path.get_ident()
+                && INTEGER_TYPES.iter().any(|type_| ident == type_)
+            {
+                result = Some(ident.clone());
+            }
+            // skip the arguments of `align(..)` and `packed(..)`
+            if meta.input.peek(syn::token::Paren) {
+                let _content;
+                syn::parenthesized!(_content in meta.input);
+            }
+            Ok(())
+        })?;
+    }
+    Ok(result)
+}
+
 #[derive(Default, Clone, Debug)]
 pub struct EnumAttr {
     // pub rename_all: Option<Inflection>,
@@ -251,6 +428,8 @@ impl_parse! {
         // "rename_all" => out.0.rename_all = Some(parse_assign_inflection(input)?),
         "tag" => out.0.tag = Some(parse_assign_str(input)?),
         "content" => out.0.content = Some(parse_assign_str(input)?),
+        // read by `ContainerAttr`, but accepted so the rest of the attribute isn't ignored
+        "deny_unknown_fields" => {},
         // "untagged" => out.0.untagged = true
     }
 }
@@ -15,6 +15,8 @@ pub use enum_def::*;
 pub use struct_def::StructDef;
 use type_reflect_core::Inflection;
 
+use crate::attribute_utils::ContainerAttr;
+
 #[derive(Clone, Debug)]
 pub enum TypeDef {
     Struct(StructDef),
@@ -99,3 +101,72 @@ impl InflectionTokenProvider for Inflection {
         self
     }
 }
+
+impl ContainerAttr {
+    /// Returns the name the type is exported under
+    pub fn export_name(&self, ident: &Ident) -> String {
+        match &self.export_name {
+            Some(export_name) => export_name.clone(),
+            None => ident.to_string(),
+        }
+    }
+
+    /// Emits the `Emittable` items which depend on the container attributes
+    pub fn emit_emittable_items(&self) -> TokenStream {
+        match &self.export_name {
+            Some(export_name) => quote! {
+                fn export_name() -> Option<&'static str> {
+                    Some(#export_name)
+                }
+            },
+            None => quote! {},
+        }
+    }
+
+    /// Emits `default_value()`, which serializes the `Default` value of the type,
+    /// if it's declared with `#[reflect(default)]`
+    pub fn emit_default_value(&self) -> TokenStream {
+        if !self.default {
+            return quote! {};
+        }
+        quote! {
+            fn default_value() -> Option<serde_json::Value> {
+                Some(
+                    serde_json::to_value(<Self as Default>::default())
+                        .expect("Failed to serialize the default value"),
+                )
+            }
+        }
+    }
+
+    /// Emits `deny_unknown_fields()`, if the type is declared with `#[serde(deny_unknown_fields)]`
+    pub fn emit_deny_unknown_fields(&self) -> TokenStream {
+        if !self.deny_unknown_fields {
+            return quote! {};
+        }
+        quote! {
+            fn deny_unknown_fields() -> bool {
+                true
+            }
+        }
+    }
+
+    /// Emits an early return from `Emittable::emit_with` for the emitters
+    /// excluded by `emitters(..)` and `skip_emitters(..)`
+    pub fn emit_emitter_filter(&self) -> TokenStream {
+        let skip_emitters = &self.skip_emitters;
+        match &self.emitters {
+            Some(emitters) => quote! {
+                if !emitter_allowed::<E>(Some(&[#(#emitters),*]), &[#(#skip_emitters),*]) {
+                    return String::new();
+                }
+            },
+            None if skip_emitters.is_empty() => quote! {},
+            None => quote! {
+                if !emitter_allowed::<E>(None, &[#(#skip_emitters),*]) {
+                    return String::new();
+                }
+            },
+        }
+    }
+}
@@ -2,10 +2,11 @@ use super::syn_type_utils::*;
 use super::type_utils::*;
 use super::InflectionTokenProvider;
 use super::RustTypeEmitter;
-use crate::attribute_utils::RenameAllAttr;
+use crate::attribute_utils::{parse_docs, ContainerAttr, RenameAllAttr, SerdeMemberAttr};
 use proc_macro2::{Ident, TokenStream};
 use quote::quote;
 use syn::{ItemStruct, Result};
+use type_reflect_core::Documentation;
 use type_reflect_core::Inflection;
 use type_reflect_core::TypeFieldsDefinition;
 
@@ -15,6 +16,8 @@ pub struct StructDef {
     inflection: Inflection,
     ident: Ident,
     fields: TypeFieldsDefinition,
+    docs: Documentation,
+    attributes: ContainerAttr,
 }
 
 // fn extract_members(item: &ItemStruct) -> Result<TypeFieldsDefinition> {
@@ -28,11 +31,23 @@ pub struct StructDef {
 impl StructDef {
     pub fn new(item: &ItemStruct) -> Result<Self> {
         let rename_attr = RenameAllAttr::from_attrs(&item.attrs)?;
+        let container_attr = ContainerAttr::from_attrs(&item.attrs)?;
+        let mut fields = item.fields.to_fields()?;
+        // `#[serde(default)]` on the struct allows any field to be omitted from the input
+        if SerdeMemberAttr::from_attrs(&item.attrs).directions.default
+            && let TypeFieldsDefinition::Named(named) = &mut fields
+        {
+            for field in named {
+                field.directions.default = true;
+            }
+        }
         Ok(Self {
             tokens: quote! { #item },
-            inflection: rename_attr.rename_all,
+            inflection: container_attr.rename_all.unwrap_or(rename_attr.rename_all),
             ident: item.ident.clone(),
-            fields: item.fields.to_fields()?,
+            fields,
+            docs: parse_docs(&item.attrs)?,
+            attributes: container_attr,
         })
     }
 
@@ -50,18 +65,33 @@ impl StructDef {
 
     pub fn emit(&self) -> TokenStream {
         let ident = &self.ident();
-        let name_literal = format!("{}", ident);
+        let name_literal = self.attributes.export_name(ident);
+        let emittable_items = self.attributes.emit_emittable_items();
+        let emitter_filter = self.attributes.emit_emitter_filter();
         let members = &self.emit_fields();
         let rust = format!("{}", self.tokens());
         let inflection = &self.inflection.to_tokens();
+        let docs = &self.docs.emit_documentation();
+        let meta = self.attributes.meta.emit_metadata();
+        let default_value = self.attributes.emit_default_value();
+        let deny_unknown_fields = self.attributes.emit_deny_unknown_fields();
         quote! {
 
+
+            #[allow(deprecated)]
             impl Emittable for #ident {
+                #emittable_items
+                fn definition() -> Option<Definition> {
+                    Some(Definition::of_struct::<Self>())
+                }
                 fn emit_with<E: TypeEmitter>(emitter: &mut E) -> String {
+                    #emitter_filter
                     emitter.emit_struct::<Self>()
                 }
             }
 
+
+            #[allow(deprecated)]
             impl StructType for #ident {
                 fn name() -> &'static str {
                     #name_literal
@@ -75,6 +105,14 @@ impl StructDef {
                 fn rust() -> String {
                     #rust.to_string()
                 }
+                fn docs() -> Documentation {
+                    #docs
+                }
+                fn meta() -> Metadata {
+                    #meta
+                }
+                #default_value
+                #deny_unknown_fields
             }
         }
     }
@@ -1,33 +1,39 @@
-use syn::{Field, GenericArgument, PathArguments, Result, Type as SynType, TypePath};
+use crate::attribute_utils::{
+    merge_constraints, parse_docs, parse_validator_constraints, FieldAttr, SerdeMemberAttr,
+};
+use syn::{
+    spanned::Spanned, Field, GenericArgument, PathArguments, PathSegment, Result, Type as SynType,
+    TypePath,
+};
 use type_reflect_core::{
-    syn_err, NamedField, NamedType, TransparentType, TransparentTypeCase, Type,
-    TypeFieldsDefinition,
+    syn_err, InlineType, Inflection, NamedField, NamedType, StringFormat, TransparentType,
+    TransparentTypeCase, Type, TypeFieldsDefinition,
 };
 
-fn leading_segment(path: &TypePath) -> String {
-    path.path.segments[0].ident.to_string()
+/// Returns true if the path is a single identifier, i.e. `User` rather than `models::User`
+fn is_bare(path: &TypePath) -> bool {
+    path.path.leading_colon.is_none() && path.path.segments.len() == 1
 }
 
-fn generic_args(path: &TypePath) -> Result<Vec<Type>> {
-    match &path.path.segments[0].arguments {
+/// Returns the type arguments of the segment, ignoring lifetimes such as the `'a` in `Cow<'a, str>`
+fn generic_args(segment: &PathSegment) -> Result<Vec<Type>> {
+    match &segment.arguments {
         PathArguments::None => Ok(vec![]),
         PathArguments::AngleBracketed(args) => (&args.args)
             .into_iter()
+            .filter(|arg| !matches!(arg, GenericArgument::Lifetime(_)))
             .map(|arg| match arg {
                 GenericArgument::Type(inner_ty) => inner_ty.to_type(),
                 _ => syn_err!("Generic argument must be a type: {:#?}", arg),
             })
             .collect(),
-        _ => syn_err!(
-            "Argument type not supported: {:#?}",
-            &path.path.segments[0].arguments
-        ),
+        _ => syn_err!("Argument type not supported: {:#?}", &segment.arguments),
     }
 }
 
 fn simple_type(name: String) -> Type {
     match name.as_str() {
-        "String" => Type::String,
+        "String" | "str" => Type::String,
         "bool" => Type::Boolean,
         "u8" | "u16" | "u32" | "u64" => Type::UnsignedInt,
         "i8" | "i16" | "i32" | "i64" => Type::Int,
@@ -39,31 +45,122 @@ fn simple_type(name: String) -> Type {
     }
 }
 
+/// Returns the wrapper if the type is one which serde serializes as the value it contains
+fn transparent_case(name: &str) -> Option<TransparentTypeCase> {
+    match name {
+        "Box" => Some(TransparentTypeCase::Box),
+        "Rc" => Some(TransparentTypeCase::Rc),
+        "Arc" => Some(TransparentTypeCase::Arc),
+        "Mutex" => Some(TransparentTypeCase::Mutex),
+        "RwLock" => Some(TransparentTypeCase::RwLock),
+        "Cow" => Some(TransparentTypeCase::Cow),
+        "Cell" => Some(TransparentTypeCase::Cell),
+        "RefCell" => Some(TransparentTypeCase::RefCell),
+        _ => None,
+    }
+}
+
+/// Returns true if the type is `PhantomData`, which serde writes as `null`
+fn is_phantom_data(type_: &SynType) -> bool {
+    match type_ {
+        SynType::Path(type_path) => type_path
+            .path
+            .segments
+            .last()
+            .is_some_and(|segment| segment.ident == "PhantomData"),
+        _ => false,
+    }
+}
+
+/// Maps well-known third-party types to their serialized string format.
+///
+/// Each mapping is only active when the matching cargo feature is enabled.  A type is
+/// matched by a path starting with its crate, i.e. `uuid::Uuid`, since a bare name such
+/// as `Decimal` may just as well be a local type.  A bare `DateTime` is matched by its
+/// time zone argument, i.e. `DateTime<Utc>`.
+fn third_party_type(path: &TypePath) -> Option<Type> {
+    if path.qself.is_some() {
+        return None;
+    }
+    let segments = &path.path.segments;
+    let last = segments.last()?;
+    let crate_name = match segments.len() {
+        1 => None,
+        _ => Some(segments[0].ident.to_string()),
+    };
+    let name = last.ident.to_string();
+    let format: Option<StringFormat> = match (crate_name.as_deref(), name.as_str()) {
+        #[cfg(feature = "chrono")]
+        (Some("chrono"), "DateTime") => Some(StringFormat::DateTime),
+        #[cfg(feature = "chrono")]
+        (None, "DateTime") if matches!(last.arguments, PathArguments::AngleBracketed(_)) => {
+            Some(StringFormat::DateTime)
+        }
+        #[cfg(feature = "chrono")]
+        (Some("chrono"), "NaiveDateTime") => Some(StringFormat::NaiveDateTime),
+        #[cfg(feature = "chrono")]
+        (Some("chrono"), "NaiveDate") => Some(StringFormat::Date),
+        #[cfg(feature = "chrono")]
+        (Some("chrono"), "NaiveTime") => Some(StringFormat::Time),
+        #[cfg(feature = "uuid")]
+        (Some("uuid"), "Uuid") => Some(StringFormat::Uuid),
+        #[cfg(feature = "url")]
+        (Some("url"), "Url") => Some(StringFormat::Url),
+        #[cfg(feature = "rust_decimal")]
+        (Some("rust_decimal"), "Decimal") => Some(StringFormat::Decimal),
+        _ => None,
+    };
+    format.map(Type::FormattedString)
+}
+
+/// Maps a field serialized with a well-known `#[serde(with = "..")]` module to its format
+///
+/// The default serialization of the `time` types isn't a standard format, so they're
+/// only mapped when the field uses one of the RFC 3339 modules.
+fn serialized_with_type(serde_attr: &SerdeMemberAttr) -> Option<Type> {
+    match serde_attr.with.as_deref()? {
+        #[cfg(feature = "time")]
+        "time::serde::rfc3339" => Some(Type::FormattedString(StringFormat::DateTime)),
+        #[cfg(feature = "time")]
+        "time::serde::rfc3339::option" => Some(Type::Option(
+            Type::FormattedString(StringFormat::DateTime).into(),
+        )),
+        _ => None,
+    }
+}
+
 pub trait SynTypeBridge {
     fn syn_type(&self) -> &syn::Type;
     fn to_type(&self) -> Result<Type> {
+        if is_phantom_data(self.syn_type()) {
+            return Ok(Type::Null);
+        }
+        if let SynType::Path(type_path) = self.syn_type()
+            && let Some(type_) = third_party_type(type_path)
+        {
+            return Ok(type_);
+        }
         match self.syn_type() {
-            SynType::Path(type_path)
-                if type_path.qself.is_none()
-                    && type_path.path.leading_colon.is_none()
-                    && type_path.path.segments.len() == 1 =>
-            {
-                let leading = leading_segment(type_path);
-                let generics = generic_args(type_path)?;
-                match leading.as_str() {
-                    "Option" if generics.len() == 1 => Ok(Type::Option(generics[0].clone().into())),
-                    "Box" if generics.len() == 1 => Ok(Type::Transparent(TransparentType {
-                        case: TransparentTypeCase::Box,
-                        type_: generics[0].clone().into(),
-                    })),
-                    "Rc" if generics.len() == 1 => Ok(Type::Transparent(TransparentType {
-                        case: TransparentTypeCase::Rc,
-                        type_: generics[0].clone().into(),
-                    })),
-                    "Arc" if generics.len() == 1 => Ok(Type::Transparent(TransparentType {
-                        case: TransparentTypeCase::Arc,
+            // The standard wrappers and collections are matched by their last segment,
+            // so that paths such as `std::sync::Mutex<T>` are supported
+            SynType::Path(type_path) if type_path.qself.is_none() => {
+                let segment = type_path
+                    .path
+                    .segments
+                    .last()
+                    .expect("a path has at least one segment");
+                let name = segment.ident.to_string();
+                let generics = generic_args(segment)?;
+                if generics.len() == 1
+                    && let Some(case) = transparent_case(&name)
+                {
+                    return Ok(Type::Transparent(TransparentType {
+                        case,
                         type_: generics[0].clone().into(),
-                    })),
+                    }));
+                }
+                match name.as_str() {
+                    "Option" if generics.len() == 1 => Ok(Type::Option(generics[0].clone().into())),
                     "Vec" if generics.len() == 1 => Ok(Type::Array(generics[0].clone().into())),
                     "HashMap" if generics.len() == 2 => Ok(Type::Map {
                         key: generics[0].clone().into(),
@@ -73,7 +170,7 @@ pub trait SynTypeBridge {
                         key: generics[0].clone().into(),
                         value: generics[1].clone().into(),
                     }),
-                    _ if generics.is_empty() => Ok(simple_type(leading)),
+                    _ if generics.is_empty() && is_bare(type_path) => Ok(simple_type(name)),
                     _ => syn_err!("Unsupported type type: {:#?}", &self.syn_type()),
                 }
             }
@@ -88,6 +185,11 @@ impl SynTypeBridge for syn::Type {
     }
 }
 
+/// Returns true if the field is skipped by either `#[reflect(skip)]` or `#[serde(skip)]`
+fn is_skipped(field: &Field) -> Result<bool> {
+    Ok(FieldAttr::from_attrs(&field.attrs)?.skip || SerdeMemberAttr::from_attrs(&field.attrs).skip)
+}
+
 fn get_struct_member(field: &Field) -> Result<NamedField> {
     // println!("Getting struct member from field: {:#?}", field);
     let name = match &field.ident {
@@ -95,9 +197,23 @@ fn get_struct_member(field: &Field) -> Result<NamedField> {
         Some(ident) => format!("{}", ident),
     };
 
-    let type_ = field.ty.to_type()?;
+    let attr = FieldAttr::from_attrs(&field.attrs)?;
+    let serde_attr = SerdeMemberAttr::from_attrs(&field.attrs);
+    let rename = attr.rename.clone().or(serde_attr.rename);
+    let meta = attr.meta.clone();
+    let option_style = attr.option_style;
+    let type_ = field_type(field, attr)?;
+    let docs = parse_docs(&field.attrs)?;
 
-    Ok(NamedField { name, type_ })
+    Ok(NamedField {
+        name,
+        rename,
+        type_,
+        docs,
+        meta,
+        directions: serde_attr.directions,
+        option_style,
+    })
 }
 
 fn get_field_type(field: &Field) -> Result<Type> {
@@ -107,9 +223,55 @@ fn get_field_type(field: &Field) -> Result<Type> {
         Some(_ident) => panic!("Tuple fields must not be named: {:#?}", field),
     };
 
-    let type_ = field.ty.to_type()?;
+    field_type(field, FieldAttr::from_attrs(&field.attrs)?)
+}
+
+/// Returns the type of a field, adjusted by the field attributes.
+///
+/// Optional fields keep the `Option` outermost, so an override, inlined
+/// definition or constraint applies to the inner type.
+fn field_type(field: &Field, attr: FieldAttr) -> Result<Type> {
+    let type_ = match serialized_with_type(&SerdeMemberAttr::from_attrs(&field.attrs)) {
+        Some(type_) => type_,
+        None => field.ty.to_type()?,
+    };
+    let (inner, is_option) = match type_ {
+        Type::Option(inner) => (*inner, true),
+        type_ => (type_, false),
+    };
+
+    let inner = match inner {
+        Type::Named(named) if attr.inline => Type::Inline(InlineType {
+            name: named.name,
+            fields: TypeFieldsDefinition::Unit,
+            inflection: Inflection::None,
+        }),
+        _ if attr.inline => syn_err!(field.ty.span(); "only named types can be inlined"),
+        inner => inner,
+    };
 
-    Ok(type_)
+    let inner = match attr.has_override() {
+        true => Type::Override {
+            type_: inner.into(),
+            overrides: attr.type_override,
+        },
+        false => inner,
+    };
+
+    let mut constraints = attr.constraints;
+    merge_constraints(&mut constraints, parse_validator_constraints(&field.attrs));
+    let inner = match constraints.is_empty() {
+        true => inner,
+        false => Type::Constrained {
+            type_: inner.into(),
+            constraints,
+        },
+    };
+
+    Ok(match is_option || attr.optional {
+        true => Type::Option(inner.into()),
+        false => inner,
+    })
 }
 
 pub trait FieldsBridge {
@@ -134,10 +296,13 @@ impl FieldsBridge for syn::Fields {
 pub trait FieldsNamedBridge {
     fn fields_named(&self) -> &syn::FieldsNamed;
     fn to_named_fields(&self) -> Result<Vec<NamedField>> {
-        (&self.fields_named().named)
-            .into_iter()
-            .map(|field: &Field| get_struct_member(field))
-            .collect()
+        let mut fields = vec![];
+        for field in &self.fields_named().named {
+            if !is_skipped(field)? {
+                fields.push(get_struct_member(field)?);
+            }
+        }
+        Ok(fields)
     }
 }
 
@@ -150,10 +315,14 @@ impl FieldsNamedBridge for syn::FieldsNamed {
 pub trait FieldsUnmnamedBridge {
     fn fields_unnamed(&self) -> &syn::FieldsUnnamed;
     fn to_tuple_members(&self) -> Result<Vec<Type>> {
-        (&self.fields_unnamed().unnamed)
-            .into_iter()
-            .map(|field: &Field| get_field_type(field))
-            .collect()
+        let mut fields = vec![];
+        for field in &self.fields_unnamed().unnamed {
+            if is_skipped(field)? {
+                continue;
+            }
+            fields.push(get_field_type(field)?);
+        }
+        Ok(fields)
     }
 }
 
@@ -1,15 +1,18 @@
 use super::syn_type_utils::SynTypeBridge;
-use super::type_utils::TypeBridge;
+use super::type_utils::{DocumentationBridge, MetadataBridge, TypeBridge};
+use crate::attribute_utils::{parse_docs, ContainerAttr};
 use proc_macro2::TokenStream;
 use quote::quote;
 use syn::{Ident, ItemType, Result};
-use type_reflect_core::Type;
+use type_reflect_core::{Documentation, Type};
 
 #[derive(Clone, Debug)]
 pub struct TypeAliasDef {
     pub tokens: TokenStream,
     pub ident: Ident,
     source_type: Type,
+    docs: Documentation,
+    attributes: ContainerAttr,
 }
 
 impl TypeAliasDef {
@@ -18,22 +21,37 @@ impl TypeAliasDef {
             tokens: quote! { #item },
             ident: item.ident.clone(),
             source_type: (item.ty).to_type()?,
+            docs: parse_docs(&item.attrs)?,
+            attributes: ContainerAttr::from_attrs(&item.attrs)?,
         })
     }
 
     pub fn emit(&self) -> TokenStream {
         let ident = &self.ident;
-        let name_literal = format!("{}", ident);
+        let name_literal = self.attributes.export_name(ident);
+        let emittable_items = self.attributes.emit_emittable_items();
+        let emitter_filter = self.attributes.emit_emitter_filter();
         let rust = format!("{}", self.tokens);
         let type_ = self.source_type.emit_type();
+        let docs = self.docs.emit_documentation();
+        let meta = self.attributes.meta.emit_metadata();
+        let default_value = self.attributes.emit_default_value();
 
         quote! {
+            #[allow(deprecated)]
             impl Emittable for #ident {
+                #emittable_items
+                fn definition() -> Option<Definition> {
+                    Some(Definition::of_alias::<Self>())
+                }
                 fn emit_with<E: TypeEmitter>(emitter: &mut E) -> String {
+                    #emitter_filter
                     emitter.emit_alias::<Self>()
                 }
             }
 
+
+            #[allow(deprecated)]
             impl AliasType for #ident {
                 fn name() -> &'static str {
                     #name_literal
@@ -44,6 +62,13 @@ impl TypeAliasDef {
                 fn rust() -> String {
                     #rust.to_string()
                 }
+                fn docs() -> Documentation {
+                    #docs
+                }
+                fn meta() -> Metadata {
+                    #meta
+                }
+                #default_value
             }
         }
     }
@@ -11,10 +11,15 @@ pub trait TypeBridge {
                 quote! { Type::Named(#named_type) }
             }
             Type::String => quote! { Type::String },
+            Type::FormattedString(format) => {
+                let format = format.emit_string_format();
+                quote! { Type::FormattedString(#format) }
+            }
             Type::Int => quote! { Type::Int },
             Type::UnsignedInt => quote! { Type::UnsignedInt },
             Type::Float => quote! { Type::Float },
             Type::Boolean => quote! { Type::Boolean },
+            Type::Null => quote! { Type::Null },
             Type::Option(t) => {
                 let inner = t.emit_type();
                 quote! { Type::Option( #inner.into() ) }
@@ -32,6 +37,27 @@ pub trait TypeBridge {
                 let inner = t.emit_transparent_type();
                 quote! { Type::Transparent( #inner ) }
             }
+            Type::Override { type_, overrides } => {
+                let inner = type_.emit_type();
+                let overrides = overrides.emit_type_override();
+                quote! { Type::Override{ type_: #inner.into(), overrides: #overrides } }
+            }
+            Type::Constrained { type_, constraints } => {
+                let inner = type_.emit_type();
+                let constraints = constraints.emit_constraints();
+                quote! { Type::Constrained{ type_: #inner.into(), constraints: #constraints } }
+            }
+            Type::Inline(inline) => {
+                let name = &inline.name;
+                let ident = format_ident!("{}", name);
+                quote! {
+                    Type::Inline(InlineType {
+                        name: #name.to_string(),
+                        fields: <#ident as StructType>::fields(),
+                        inflection: <#ident as StructType>::inflection(),
+                    })
+                }
+            }
         }
     }
 }
@@ -82,11 +108,27 @@ pub trait NamedFieldBridge {
     fn emit_member(&self) -> TokenStream {
         let member = &self.member();
         let name = &member.name;
+        let rename = emit_option_string(&member.rename);
         let type_ = member.type_.emit_type();
+        let docs = member.docs.emit_documentation();
+        let meta = member.meta.emit_metadata();
+        let directions = member.directions.emit_directions();
+        let option_style = match member.option_style {
+            Some(style) => {
+                let style = style.emit_option_style();
+                quote! { Some(#style) }
+            }
+            None => quote! { None },
+        };
         quote! {
             NamedField {
                 name: #name.to_string(),
+                rename: #rename,
                 type_: #type_,
+                docs: #docs,
+                meta: #meta,
+                directions: #directions,
+                option_style: #option_style,
             }
         }
     }
@@ -136,6 +178,11 @@ pub trait TransparentTypeBridge {
             TransparentTypeCase::Box => quote! { TransparentTypeCase::Box },
             TransparentTypeCase::Rc => quote! { TransparentTypeCase::Rc },
             TransparentTypeCase::Arc => quote! { TransparentTypeCase::Arc },
+            TransparentTypeCase::Mutex => quote! { TransparentTypeCase::Mutex },
+            TransparentTypeCase::RwLock => quote! { TransparentTypeCase::RwLock },
+            TransparentTypeCase::Cow => quote! { TransparentTypeCase::Cow },
+            TransparentTypeCase::Cell => quote! { TransparentTypeCase::Cell },
+            TransparentTypeCase::RefCell => quote! { TransparentTypeCase::RefCell },
         };
         let inner = &self.transparent_type().type_.emit_type();
 
@@ -153,3 +200,196 @@ impl TransparentTypeBridge for TransparentType {
         self
     }
 }
+
+pub trait StringFormatBridge {
+    fn string_format(&self) -> &StringFormat;
+    fn emit_string_format(&self) -> TokenStream {
+        match &self.string_format() {
+            StringFormat::DateTime => quote! { StringFormat::DateTime },
+            StringFormat::NaiveDateTime => quote! { StringFormat::NaiveDateTime },
+            StringFormat::Date => quote! { StringFormat::Date },
+            StringFormat::Time => quote! { StringFormat::Time },
+            StringFormat::Uuid => quote! { StringFormat::Uuid },
+            StringFormat::Url => quote! { StringFormat::Url },
+            StringFormat::Decimal => quote! { StringFormat::Decimal },
+        }
+    }
+}
+
+impl StringFormatBridge for StringFormat {
+    fn string_format(&self) -> &StringFormat {
+        self
+    }
+}
+
+pub fn emit_option_string(value: &Option<String>) -> TokenStream {
+    match value {
+        Some(value) => quote! { Some(#value.to_string()) },
+        None => quote! { None },
+    }
+}
+
+pub trait TypeOverrideBridge {
+    fn type_override(&self) -> &TypeOverride;
+    fn emit_type_override(&self) -> TokenStream {
+        let type_override = self.type_override();
+        let type_script = emit_option_string(&type_override.type_script);
+        let zod = emit_option_string(&type_override.zod);
+        let validation = emit_option_string(&type_override.validation);
+
+        quote! {
+            TypeOverride {
+                type_script: #type_script,
+                zod: #zod,
+                validation: #validation,
+            }
+        }
+    }
+}
+
+impl TypeOverrideBridge for TypeOverride {
+    fn type_override(&self) -> &TypeOverride {
+        self
+    }
+}
+
+pub trait DocumentationBridge {
+    fn documentation(&self) -> &Documentation;
+    fn emit_documentation(&self) -> TokenStream {
+        let documentation = self.documentation();
+        let description = emit_option_string(&documentation.description);
+        let deprecated = emit_option_string(&documentation.deprecated);
+
+        quote! {
+            Documentation {
+                description: #description,
+                deprecated: #deprecated,
+            }
+        }
+    }
+}
+
+impl DocumentationBridge for Documentation {
+    fn documentation(&self) -> &Documentation {
+        self
+    }
+}
+
+pub trait MetadataBridge {
+    fn metadata(&self) -> &Metadata;
+    fn emit_metadata(&self) -> TokenStream {
+        let entries: Vec<TokenStream> = self
+            .metadata()
+            .iter()
+            .map(|(key, value)| {
+                let value = match value {
+                    MetaValue::String(value) => quote! { MetaValue::String(#value.to_string()) },
+                    MetaValue::Int(value) => quote! { MetaValue::Int(#value) },
+                    MetaValue::Float(value) => quote! { MetaValue::Float(#value) },
+                    MetaValue::Bool(value) => quote! { MetaValue::Bool(#value) },
+                };
+                quote! { .with(#key, #value) }
+            })
+            .collect();
+
+        quote! {
+            Metadata::new()#(#entries)*
+        }
+    }
+}
+
+impl MetadataBridge for Metadata {
+    fn metadata(&self) -> &Metadata {
+        self
+    }
+}
+
+pub trait ConstraintsBridge {
+    fn constraints(&self) -> &Constraints;
+    fn emit_constraints(&self) -> TokenStream {
+        fn emit_option<T: ToTokens>(value: &Option<T>) -> TokenStream {
+            match value {
+                Some(value) => quote! { Some(#value) },
+                None => quote! { None },
+            }
+        }
+
+        let constraints = self.constraints();
+        let min_len = emit_option(&constraints.min_len);
+        let max_len = emit_option(&constraints.max_len);
+        let min = emit_option(&constraints.min);
+        let max = emit_option(&constraints.max);
+        let pattern = emit_option_string(&constraints.pattern);
+        let email = constraints.email;
+        let url = constraints.url;
+
+        quote! {
+            Constraints {
+                min_len: #min_len,
+                max_len: #max_len,
+                min: #min,
+                max: #max,
+                pattern: #pattern,
+                email: #email,
+                url: #url,
+            }
+        }
+    }
+}
+
+impl ConstraintsBridge for Constraints {
+    fn constraints(&self) -> &Constraints {
+        self
+    }
+}
+
+pub trait FieldDirectionsBridge {
+    fn directions(&self) -> &FieldDirections;
+    fn emit_directions(&self) -> TokenStream {
+        let FieldDirections {
+            serialize_name,
+            deserialize_name,
+            aliases,
+            skip_serializing,
+            skip_deserializing,
+            skip_serializing_if,
+            default,
+        } = self.directions();
+        let serialize_name = emit_option_string(serialize_name);
+        let deserialize_name = emit_option_string(deserialize_name);
+        quote! {
+            FieldDirections {
+                serialize_name: #serialize_name,
+                deserialize_name: #deserialize_name,
+                aliases: vec![#(#aliases.to_string()),*],
+                skip_serializing: #skip_serializing,
+                skip_deserializing: #skip_deserializing,
+                skip_serializing_if: #skip_serializing_if,
+                default: #default,
+            }
+        }
+    }
+}
+
+impl FieldDirectionsBridge for FieldDirections {
+    fn directions(&self) -> &FieldDirections {
+        self
+    }
+}
+
+pub trait OptionStyleBridge {
+    fn option_style(&self) -> &OptionStyle;
+    fn emit_option_style(&self) -> TokenStream {
+        match self.option_style() {
+            OptionStyle::Optional => quote! { OptionStyle::Optional },
+            OptionStyle::Nullable => quote! { OptionStyle::Nullable },
+            OptionStyle::Nullish => quote! { OptionStyle::Nullish },
+        }
+    }
+}
+
+impl OptionStyleBridge for OptionStyle {
+    fn option_style(&self) -> &OptionStyle {
+        self
+    }
+}